@@ -0,0 +1,81 @@
+package io.github.ayushmaanbhav.common.interceptor
+
+import jakarta.servlet.Filter
+import jakarta.servlet.FilterChain
+import jakarta.servlet.ReadListener
+import jakarta.servlet.ServletInputStream
+import jakarta.servlet.ServletRequest
+import jakarta.servlet.ServletResponse
+import jakarta.servlet.http.HttpServletRequest
+import jakarta.servlet.http.HttpServletRequestWrapper
+import jakarta.servlet.http.HttpServletResponse
+import java.io.BufferedReader
+import java.io.ByteArrayInputStream
+import java.io.InputStreamReader
+import java.nio.charset.Charset
+import org.springframework.http.HttpStatus
+import org.springframework.util.AntPathMatcher
+
+/**
+ * rejects requests whose body exceeds the limit of the first matching path pattern, or the default
+ * limit when no pattern matches, bodies without a declared length (chunked) are read up to the limit
+ * before the request is handled, so the limit holds for the bytes actually sent
+ */
+class RequestBodyLimitFilter(
+    private val defaultMaxBytes: Long,
+    private val maxBytesByPathPattern: LinkedHashMap<String, Long> = LinkedHashMap(),
+) : Filter {
+    private val pathMatcher = AntPathMatcher()
+
+    override fun doFilter(request: ServletRequest, response: ServletResponse, chain: FilterChain) {
+        val httpRequest = request as HttpServletRequest
+        val httpResponse = response as HttpServletResponse
+        val path = httpRequest.requestURI.removePrefix(httpRequest.contextPath)
+        val maxBytes = maxBytesByPathPattern.entries
+            .firstOrNull { pathMatcher.match(it.key, path) }?.value ?: defaultMaxBytes
+        if (httpRequest.contentLengthLong > maxBytes) {
+            reject(httpResponse, maxBytes)
+            return
+        }
+        if (httpRequest.contentLengthLong >= 0) {
+            chain.doFilter(request, response)
+            return
+        }
+        val body = httpRequest.inputStream.readNBytes(minOf(maxBytes + 1, Int.MAX_VALUE.toLong()).toInt())
+        if (body.size > maxBytes) {
+            reject(httpResponse, maxBytes)
+            return
+        }
+        chain.doFilter(CachedBodyRequest(httpRequest, body), response)
+    }
+
+    private fun reject(response: HttpServletResponse, maxBytes: Long) =
+        response.sendError(HttpStatus.PAYLOAD_TOO_LARGE.value(), "Request body exceeds $maxBytes bytes")
+
+    private class CachedBodyRequest(request: HttpServletRequest, private val body: ByteArray) : HttpServletRequestWrapper(request) {
+        private val inputStream = CachedBodyInputStream(body)
+
+        override fun getInputStream(): ServletInputStream = inputStream
+
+        override fun getReader(): BufferedReader =
+            BufferedReader(InputStreamReader(inputStream, characterEncoding?.let(Charset::forName) ?: Charsets.UTF_8))
+
+        override fun getContentLength(): Int = body.size
+
+        override fun getContentLengthLong(): Long = body.size.toLong()
+    }
+
+    private class CachedBodyInputStream(body: ByteArray) : ServletInputStream() {
+        private val stream = ByteArrayInputStream(body)
+
+        override fun read(): Int = stream.read()
+
+        override fun read(b: ByteArray, off: Int, len: Int): Int = stream.read(b, off, len)
+
+        override fun isFinished(): Boolean = stream.available() == 0
+
+        override fun isReady(): Boolean = true
+
+        override fun setReadListener(readListener: ReadListener) = throw UnsupportedOperationException()
+    }
+}
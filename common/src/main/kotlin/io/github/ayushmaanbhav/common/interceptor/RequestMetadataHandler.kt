@@ -6,6 +6,7 @@ import io.github.ayushmaanbhav.common.model.RequestMetadata.Companion.CORRELATIO
 import io.github.ayushmaanbhav.common.model.RequestMetadata.Companion.CUSTOMER_ID_HEADER
 import io.github.ayushmaanbhav.common.model.RequestMetadata.Companion.DEVICE_ID
 import io.github.ayushmaanbhav.common.model.RequestMetadata.Companion.OS_VERSION
+import io.github.ayushmaanbhav.common.model.RequestMetadata.Companion.TENANT_ID_HEADER
 import io.github.ayushmaanbhav.common.model.RequestMetadata.Companion.X_CLICK_STREAM_DATA
 import jakarta.servlet.http.HttpServletRequest
 import jakarta.servlet.http.HttpServletResponse
@@ -15,21 +16,35 @@ import org.springframework.web.servlet.ModelAndView
 
 class RequestMetadataHandler : HandlerInterceptor {
     override fun preHandle(request: HttpServletRequest, response: HttpServletResponse, handler: Any): Boolean {
-        (request.getHeader(CORRELATION_ID_HEADER) ?: UUID.randomUUID().toString()).let { RequestMetadata.setCorrelationId(it) }
+        (request.getHeader(CORRELATION_ID_HEADER) ?: UUID.randomUUID().toString()).let {
+            RequestMetadata.setCorrelationId(it)
+            response.setHeader(CORRELATION_ID_HEADER, it)
+        }
         request.getHeader(OS_VERSION)?.let { RequestMetadata.setOsVersion(it) }
         request.getHeader(DEVICE_ID)?.let { RequestMetadata.setDeviceId(it) }
         request.getHeader(CUSTOMER_ID_HEADER)?.let { RequestMetadata.setCustomerId(it) }
         request.getHeader(APP_VERSION_CODE)?.let { RequestMetadata.setAppVersionCode(it) }
         request.getHeader(X_CLICK_STREAM_DATA)?.let { RequestMetadata.setClickStreamData(it) }
+        request.getHeader(TENANT_ID_HEADER)?.let { RequestMetadata.setTenantId(it) }
         return true
     }
 
     override fun postHandle(request: HttpServletRequest, response: HttpServletResponse, handler: Any, modelAndView: ModelAndView?) {
+        resetRequestMetadata()
+    }
+
+    // postHandle is skipped when the handler throws, so the metadata is reset here as well to not leak into the next request
+    override fun afterCompletion(request: HttpServletRequest, response: HttpServletResponse, handler: Any, ex: Exception?) {
+        resetRequestMetadata()
+    }
+
+    private fun resetRequestMetadata() {
         RequestMetadata.resetCorrelationId()
         RequestMetadata.resetDeviceId()
         RequestMetadata.resetOsVersion()
         RequestMetadata.resetCustomerId()
         RequestMetadata.resetAppVersionCode()
         RequestMetadata.resetClickStreamData()
+        RequestMetadata.resetTenantId()
     }
 }
@@ -12,6 +12,8 @@ class RequestMetadata {
         const val CUSTOMER_ID = "customerId"
         const val X_CLICK_STREAM_DATA = "X-Click-Stream-Data"
         const val APP_VERSION_CODE = "appVersionCode"
+        const val TENANT_ID_HEADER = "X-Tenant-Id"
+        const val TENANT_ID = "tenantId"
 
         fun getCorrelationId(): String? = ThreadContext.get(CORRELATION_ID)
         fun getDeviceId(): String? = ThreadContext.get(DEVICE_ID)
@@ -19,6 +21,7 @@ class RequestMetadata {
         fun getCustomerId(): String? = ThreadContext.get(CUSTOMER_ID)
         fun getClickStreamData(): String? = ThreadContext.get(X_CLICK_STREAM_DATA)
         fun getAppVersionCode(): String? = ThreadContext.get(APP_VERSION_CODE)
+        fun getTenantId(): String? = ThreadContext.get(TENANT_ID)
 
         fun setCorrelationId(requestId: String) = ThreadContext.put(CORRELATION_ID, requestId)
         fun setDeviceId(deviceId: String) = ThreadContext.put(DEVICE_ID, deviceId)
@@ -26,6 +29,7 @@ class RequestMetadata {
         fun setCustomerId(customerId: String) = ThreadContext.put(CUSTOMER_ID, customerId)
         fun setClickStreamData(clickStreamData: String) = ThreadContext.put(X_CLICK_STREAM_DATA, clickStreamData)
         fun setAppVersionCode(appVersionCode: String) = ThreadContext.put(APP_VERSION_CODE, appVersionCode)
+        fun setTenantId(tenantId: String) = ThreadContext.put(TENANT_ID, tenantId)
 
         fun resetCorrelationId() = ThreadContext.remove(CORRELATION_ID)
         fun resetDeviceId() = ThreadContext.remove(DEVICE_ID)
@@ -33,5 +37,6 @@ class RequestMetadata {
         fun resetCustomerId() = ThreadContext.remove(CUSTOMER_ID)
         fun resetClickStreamData() = ThreadContext.remove(X_CLICK_STREAM_DATA)
         fun resetAppVersionCode() = ThreadContext.remove(APP_VERSION_CODE)
+        fun resetTenantId() = ThreadContext.remove(TENANT_ID)
     }
 }
@@ -3,6 +3,7 @@ package io.github.ayushmaanbhav.common.util
 import io.github.ayushmaanbhav.common.model.RequestMetadata.Companion.CORRELATION_ID
 import java.util.concurrent.CompletableFuture
 import java.util.concurrent.ExecutorService
+import java.util.concurrent.Future
 import java.util.function.Supplier
 import org.apache.logging.log4j.ThreadContext
 
@@ -28,6 +29,12 @@ class CompletableFutureWithCtx {
             return CompletableFuture.supplyAsync(supplierWithThreadContext, executorService)
         }
 
+        // unlike a CompletableFuture, cancelling the returned future interrupts the running task
+        fun <U> submit(executorService: ExecutorService, supplier: Supplier<U>): Future<U> {
+            val supplierWithThreadContext = supplyAsyncWithThreadContext(supplier)
+            return executorService.submit<U> { supplierWithThreadContext.get() }
+        }
+
         private fun runAsyncWithThreadContext(runnable: Runnable): Runnable {
             val correlationId = ThreadContext.get(CORRELATION_ID)
             return Runnable {
@@ -0,0 +1,107 @@
+package io.github.ayushmaanbhav.common.interceptor
+
+import io.kotest.core.spec.style.StringSpec
+import io.kotest.matchers.shouldBe
+import io.mockk.every
+import io.mockk.mockk
+import io.mockk.slot
+import io.mockk.verify
+import jakarta.servlet.FilterChain
+import jakarta.servlet.ReadListener
+import jakarta.servlet.ServletInputStream
+import jakarta.servlet.ServletRequest
+import jakarta.servlet.http.HttpServletRequest
+import jakarta.servlet.http.HttpServletResponse
+import java.io.ByteArrayInputStream
+import org.springframework.http.HttpStatus
+
+class RequestBodyLimitFilterTest : StringSpec() {
+    private val filter = RequestBodyLimitFilter(defaultMaxBytes = 10, maxBytesByPathPattern = linkedMapOf("/**/bulk" to 100L))
+
+    private fun request(path: String, body: ByteArray, declareLength: Boolean = true): HttpServletRequest {
+        val request = mockk<HttpServletRequest>(relaxed = true)
+        val stream = ByteArrayInputStream(body)
+        every { request.contextPath } returns "/api"
+        every { request.requestURI } returns "/api$path"
+        every { request.contentLengthLong } returns if (declareLength) body.size.toLong() else -1
+        every { request.inputStream } returns object : ServletInputStream() {
+            override fun read(): Int = stream.read()
+            override fun isFinished(): Boolean = stream.available() == 0
+            override fun isReady(): Boolean = true
+            override fun setReadListener(readListener: ReadListener) = Unit
+        }
+        return request
+    }
+
+    init {
+        "doFilter should reject a declared body over the default limit with 413" {
+            // Arrange
+            val response = mockk<HttpServletResponse>(relaxed = true)
+            val chain = mockk<FilterChain>(relaxed = true)
+
+            // Act
+            filter.doFilter(request("/product", ByteArray(11)), response, chain)
+
+            // Assert
+            verify { response.sendError(HttpStatus.PAYLOAD_TOO_LARGE.value(), any()) }
+            verify(exactly = 0) { chain.doFilter(any(), any()) }
+        }
+
+        "doFilter should pass a declared body under the default limit" {
+            // Arrange
+            val request = request("/product", ByteArray(10))
+            val response = mockk<HttpServletResponse>(relaxed = true)
+            val chain = mockk<FilterChain>(relaxed = true)
+
+            // Act
+            filter.doFilter(request, response, chain)
+
+            // Assert
+            verify { chain.doFilter(request, response) }
+            verify(exactly = 0) { response.sendError(any(), any()) }
+        }
+
+        "doFilter should apply the limit of a matching path pattern" {
+            // Arrange
+            val response = mockk<HttpServletResponse>(relaxed = true)
+            val chain = mockk<FilterChain>(relaxed = true)
+
+            // Act
+            filter.doFilter(request("/product/p1/attribute/bulk", ByteArray(50)), response, chain)
+            filter.doFilter(request("/product/p1/attribute/bulk", ByteArray(101)), response, chain)
+
+            // Assert
+            verify(exactly = 1) { chain.doFilter(any(), any()) }
+            verify(exactly = 1) { response.sendError(HttpStatus.PAYLOAD_TOO_LARGE.value(), any()) }
+        }
+
+        "doFilter should reject a body without a declared length once it exceeds the limit" {
+            // Arrange
+            val response = mockk<HttpServletResponse>(relaxed = true)
+            val chain = mockk<FilterChain>(relaxed = true)
+
+            // Act
+            filter.doFilter(request("/product", ByteArray(11), declareLength = false), response, chain)
+
+            // Assert
+            verify { response.sendError(HttpStatus.PAYLOAD_TOO_LARGE.value(), any()) }
+            verify(exactly = 0) { chain.doFilter(any(), any()) }
+        }
+
+        "doFilter should pass the read body on when a body without a declared length is under the limit" {
+            // Arrange
+            val response = mockk<HttpServletResponse>(relaxed = true)
+            val chain = mockk<FilterChain>(relaxed = true)
+            val forwardedRequest = slot<ServletRequest>()
+            every { chain.doFilter(capture(forwardedRequest), response) } returns Unit
+
+            // Act
+            filter.doFilter(request("/product", "{\"a\":1}".toByteArray(), declareLength = false), response, chain)
+
+            // Assert
+            val forwarded = forwardedRequest.captured as HttpServletRequest
+            forwarded.contentLengthLong shouldBe 7
+            forwarded.inputStream.readAllBytes().decodeToString() shouldBe "{\"a\":1}"
+        }
+    }
+}
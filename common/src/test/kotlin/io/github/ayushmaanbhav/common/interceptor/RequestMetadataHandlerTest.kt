@@ -6,8 +6,10 @@ import io.kotest.matchers.shouldBe
 import io.kotest.matchers.shouldNotBe
 import io.mockk.every
 import io.mockk.mockk
+import io.mockk.verify
 import jakarta.servlet.http.HttpServletRequest
 import jakarta.servlet.http.HttpServletResponse
+import java.util.*
 import org.springframework.web.servlet.HandlerInterceptor
 import org.springframework.web.servlet.ModelAndView
 
@@ -24,8 +26,9 @@ class RequestMetadataHandlerTest : StringSpec() {
             every { request.getHeader(RequestMetadata.CUSTOMER_ID_HEADER) } returns "customer123"
             every { request.getHeader(RequestMetadata.APP_VERSION_CODE) } returns "1.0.0"
             every { request.getHeader(RequestMetadata.X_CLICK_STREAM_DATA) } returns "data123"
+            every { request.getHeader(RequestMetadata.TENANT_ID_HEADER) } returns "tenant123"
 
-            val response = mockk<HttpServletResponse>()
+            val response = mockk<HttpServletResponse>(relaxed = true)
             val handler = mockk<Any>()
 
             // Act
@@ -39,12 +42,13 @@ class RequestMetadataHandlerTest : StringSpec() {
             RequestMetadata.getCustomerId() shouldBe "customer123"
             RequestMetadata.getAppVersionCode() shouldBe "1.0.0"
             RequestMetadata.getClickStreamData() shouldBe "data123"
+            RequestMetadata.getTenantId() shouldBe "tenant123"
         }
 
         "preHandle should set correlationId to a random UUID when correlationId header is not present" {
             // Arrange
             val request = mockk<HttpServletRequest>(relaxed = true)
-            val response = mockk<HttpServletResponse>()
+            val response = mockk<HttpServletResponse>(relaxed = true)
             val handler = mockk<Any>()
 
             // Act
@@ -55,6 +59,40 @@ class RequestMetadataHandlerTest : StringSpec() {
             RequestMetadata.getCorrelationId() shouldNotBe null
         }
 
+        "preHandle should echo the given correlationId in the response header" {
+            // Arrange
+            val request = mockk<HttpServletRequest>(relaxed = true)
+            every { request.getHeader(RequestMetadata.CORRELATION_ID_HEADER) } returns "12345"
+            val response = mockk<HttpServletResponse>(relaxed = true)
+            val handler = mockk<Any>()
+
+            // Act
+            handlerInterceptor.preHandle(request, response, handler)
+
+            // Assert
+            verify { response.setHeader(RequestMetadata.CORRELATION_ID_HEADER, "12345") }
+        }
+
+        "preHandle should generate a unique UUID correlationId per request and echo it" {
+            // Arrange
+            val request = mockk<HttpServletRequest>(relaxed = true)
+            val response = mockk<HttpServletResponse>(relaxed = true)
+            val handler = mockk<Any>()
+
+            // Act
+            handlerInterceptor.preHandle(request, response, handler)
+            val firstCorrelationId = RequestMetadata.getCorrelationId()
+            handlerInterceptor.preHandle(request, response, handler)
+            val secondCorrelationId = RequestMetadata.getCorrelationId()
+
+            // Assert
+            UUID.fromString(firstCorrelationId).toString() shouldBe firstCorrelationId
+            UUID.fromString(secondCorrelationId).toString() shouldBe secondCorrelationId
+            firstCorrelationId shouldNotBe secondCorrelationId
+            verify { response.setHeader(RequestMetadata.CORRELATION_ID_HEADER, firstCorrelationId!!) }
+            verify { response.setHeader(RequestMetadata.CORRELATION_ID_HEADER, secondCorrelationId!!) }
+        }
+
         "postHandle should reset RequestMetadata variables" {
             // Arrange
             RequestMetadata.setCorrelationId("12345")
@@ -63,6 +101,7 @@ class RequestMetadataHandlerTest : StringSpec() {
             RequestMetadata.setCustomerId("customer123")
             RequestMetadata.setAppVersionCode("1.0.0")
             RequestMetadata.setClickStreamData("data123")
+            RequestMetadata.setTenantId("tenant123")
 
             val request = mockk<HttpServletRequest>()
             val response = mockk<HttpServletResponse>()
@@ -79,6 +118,24 @@ class RequestMetadataHandlerTest : StringSpec() {
             RequestMetadata.getCustomerId() shouldBe null
             RequestMetadata.getAppVersionCode() shouldBe null
             RequestMetadata.getClickStreamData() shouldBe null
+            RequestMetadata.getTenantId() shouldBe null
+        }
+
+        "afterCompletion should reset RequestMetadata variables when the handler failed" {
+            // Arrange
+            RequestMetadata.setCorrelationId("12345")
+            RequestMetadata.setTenantId("tenant123")
+
+            val request = mockk<HttpServletRequest>()
+            val response = mockk<HttpServletResponse>()
+            val handler = mockk<Any>()
+
+            // Act
+            handlerInterceptor.afterCompletion(request, response, handler, RuntimeException())
+
+            // Assert
+            RequestMetadata.getCorrelationId() shouldBe null
+            RequestMetadata.getTenantId() shouldBe null
         }
     }
 }
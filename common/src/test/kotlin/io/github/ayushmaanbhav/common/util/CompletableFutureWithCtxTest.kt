@@ -5,7 +5,9 @@ import io.kotest.core.spec.style.StringSpec
 import io.kotest.matchers.shouldBe
 import java.util.*
 import java.util.concurrent.CompletableFuture
+import java.util.concurrent.CountDownLatch
 import java.util.concurrent.Executors
+import java.util.concurrent.TimeUnit
 import org.apache.logging.log4j.ThreadContext
 
 class CompletableFutureWithCtxTest : StringSpec({
@@ -38,4 +40,25 @@ class CompletableFutureWithCtxTest : StringSpec({
         }
         randomString shouldBe completableFuture2.get()
     }
+
+    "submit should execute the given supplier with the ThreadContext variables set and interrupt it on cancel" {
+        val correlationId = UUID.randomUUID().toString()
+        ThreadContext.put(RequestMetadata.CORRELATION_ID, correlationId)
+        val executorService = Executors.newSingleThreadExecutor()
+        val started = CountDownLatch(1)
+        val interrupted = CountDownLatch(1)
+        val future = CompletableFutureWithCtx.submit(executorService) {
+            correlationId shouldBe ThreadContext.get(RequestMetadata.CORRELATION_ID)
+            started.countDown()
+            try {
+                Thread.sleep(10_000)
+            } catch (e: InterruptedException) {
+                interrupted.countDown()
+            }
+        }
+        started.await(1, TimeUnit.SECONDS) shouldBe true
+        future.cancel(true)
+        interrupted.await(1, TimeUnit.SECONDS) shouldBe true
+        executorService.shutdown()
+    }
 })
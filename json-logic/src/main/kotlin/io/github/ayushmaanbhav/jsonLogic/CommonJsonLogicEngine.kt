@@ -1,6 +1,9 @@
 package io.github.ayushmaanbhav.jsonLogic
 
+import io.github.ayushmaanbhav.jsonLogic.api.EvaluationInterruptedException
+import io.github.ayushmaanbhav.jsonLogic.api.InvalidDateException
 import io.github.ayushmaanbhav.jsonLogic.api.LogicEvaluator
+import io.github.ayushmaanbhav.jsonLogic.api.MissingVariableException
 
 internal class CommonJsonLogicEngine(private val evaluator: LogicEvaluator) : JsonLogicEngine {
     override fun evaluate(expression: Map<String, Any?>, data: Any?): JsonLogicResult =
@@ -14,7 +17,14 @@ internal class CommonJsonLogicEngine(private val evaluator: LogicEvaluator) : Js
         evaluator.evaluateLogic(expression, data)
     }.fold(
         onSuccess = ::toJsonLogicResult,
-        onFailure = { JsonLogicResult.Failure.MissingOperation }
+        onFailure = {
+            when (it) {
+                is MissingVariableException -> JsonLogicResult.Failure.MissingVariable(it.path)
+                is InvalidDateException -> JsonLogicResult.Failure.InvalidDate(it.value)
+                is EvaluationInterruptedException -> JsonLogicResult.Failure.Interrupted
+                else -> JsonLogicResult.Failure.MissingOperation
+            }
+        }
     )
 
     private fun toJsonLogicResult(evaluatedValue: Any?) = evaluatedValue?.let { notNullResult ->
@@ -32,6 +32,7 @@ import io.github.ayushmaanbhav.jsonLogic.operations.numeric.Max
 import io.github.ayushmaanbhav.jsonLogic.operations.numeric.Min
 import io.github.ayushmaanbhav.jsonLogic.operations.numeric.Modulo
 import io.github.ayushmaanbhav.jsonLogic.operations.numeric.Multiplication
+import io.github.ayushmaanbhav.jsonLogic.operations.numeric.Power
 import io.github.ayushmaanbhav.jsonLogic.operations.numeric.Subtraction
 import io.github.ayushmaanbhav.jsonLogic.operations.numeric.compare.GreaterThan
 import io.github.ayushmaanbhav.jsonLogic.operations.numeric.compare.GreaterThanOrEqualTo
@@ -73,6 +74,7 @@ interface JsonLogicEngine {
             "*" to Multiplication,
             "/" to Division,
             "%" to Modulo,
+            "pow" to Power,
 
             // logic
             "==" to Equals,
@@ -9,6 +9,9 @@ sealed class JsonLogicResult {
         object MissingOperation : Failure()
         object InvalidFormat : Failure()
         object StreamIOError : Failure()
+        data class MissingVariable(val path: String) : Failure()
+        data class InvalidDate(val value: String) : Failure()
+        object Interrupted : Failure()
     }
 
 }
@@ -0,0 +1,3 @@
+package io.github.ayushmaanbhav.jsonLogic.api
+
+class EvaluationInterruptedException : Exception("Evaluation was interrupted")
@@ -0,0 +1,3 @@
+package io.github.ayushmaanbhav.jsonLogic.api
+
+class InvalidDateException(val value: String) : Exception("Date $value is invalid or out of range")
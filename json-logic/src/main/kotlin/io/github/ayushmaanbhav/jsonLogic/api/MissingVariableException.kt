@@ -0,0 +1,3 @@
+package io.github.ayushmaanbhav.jsonLogic.api
+
+class MissingVariableException(val path: String) : Exception("Variable $path is missing in data")
@@ -1,10 +1,18 @@
 package io.github.ayushmaanbhav.jsonLogic.config
 
-data class StandardLogicOperationConfig(val mathContext: MathContext, val nestedVariablePathDelimiter: Char) {
+import java.util.Locale
+
+data class StandardLogicOperationConfig(
+    val mathContext: MathContext,
+    val nestedVariablePathDelimiter: Char,
+    val locale: Locale = DEFAULT_LOCALE,
+    val errorOnMissingVariable: Boolean = false,
+) {
     constructor(mathContext: MathContext) : this(mathContext, DEFAULT_NESTED_VARIABLE_PATH_DELIMITER)
 
     companion object {
         private const val DEFAULT_NESTED_VARIABLE_PATH_DELIMITER = '.'
+        private val DEFAULT_LOCALE = Locale.US
         val DEFAULT = StandardLogicOperationConfig(MathContext.DEFAULT, DEFAULT_NESTED_VARIABLE_PATH_DELIMITER)
     }
 }
@@ -1,5 +1,6 @@
 package io.github.ayushmaanbhav.jsonLogic.evaluation
 
+import io.github.ayushmaanbhav.jsonLogic.api.EvaluationInterruptedException
 import io.github.ayushmaanbhav.jsonLogic.api.JsonLogicException
 import io.github.ayushmaanbhav.jsonLogic.api.LogicEvaluator
 import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
@@ -22,6 +23,10 @@ internal class CommonLogicEvaluator(
     }
 
     private fun executeOperation(logic: Map<*, *>, data: Any?): Any? {
+        // lets a cancelled evaluation stop, the flag is kept so operations catching the exception stop at the next one
+        if (Thread.currentThread().isInterrupted) {
+            throw EvaluationInterruptedException()
+        }
         val operator = logic.keys.firstOrNull()
         val values = logic[operator]
         return if (operations.functionalOperations.keys.contains(operator)) {
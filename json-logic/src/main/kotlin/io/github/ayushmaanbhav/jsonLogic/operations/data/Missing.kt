@@ -7,7 +7,7 @@ import io.github.ayushmaanbhav.jsonLogic.utils.asList
 internal object Missing : StandardLogicOperation {
     override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): List<Any?> {
         return expression.asList.mapNotNull {
-            it.takeIf { Var.evaluateLogic(config, it, data).isNullOrEmptyString() }
+            it.takeIf { Var.fetchValue(config, it, data).isNullOrEmptyString() }
         }
     }
 
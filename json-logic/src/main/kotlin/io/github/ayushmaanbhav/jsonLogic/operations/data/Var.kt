@@ -1,5 +1,6 @@
 package io.github.ayushmaanbhav.jsonLogic.operations.data
 
+import io.github.ayushmaanbhav.jsonLogic.api.MissingVariableException
 import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
 import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
 import io.github.ayushmaanbhav.jsonLogic.operations.data.unwrap.ValueFetchingUnwrapStrategy
@@ -8,8 +9,20 @@ import io.github.ayushmaanbhav.jsonLogic.utils.intOrZero
 import io.github.ayushmaanbhav.jsonLogic.utils.secondOrNull
 
 internal object Var : StandardLogicOperation, ValueFetchingUnwrapStrategy {
-    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
-        unwrapDataKeys(expression.asList, config.nestedVariablePathDelimiter)?.fetchValueOrDefault( expression, data)
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? {
+        val keys = unwrapDataKeys(expression.asList, config.nestedVariablePathDelimiter)
+        val value = keys?.fetchValueOrDefault(expression, data)
+        if (config.errorOnMissingVariable && value == null && keys != null && !hasDefaultValue(expression) && keys.isMissingIn(data)) {
+            throw MissingVariableException(keys.joinToString(config.nestedVariablePathDelimiter.toString()))
+        }
+        return value
+    }
+
+    /**
+     * fetches the value without failing on missing variables, for operations which check for missing values themselves
+     */
+    internal fun fetchValue(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        unwrapDataKeys(expression.asList, config.nestedVariablePathDelimiter)?.fetchValueOrDefault(expression, data)
 
     private fun List<String>.fetchValueOrDefault(expression: Any?, data: Any?): Any? {
         val value = if (isNotEmpty()) {
@@ -45,8 +58,25 @@ internal object Var : StandardLogicOperation, ValueFetchingUnwrapStrategy {
     }
 
     private fun shouldUseDefaultValue(value: Any?, expression: Any?) = (value == expression || value == null)
-        && expression is List<*>
-        && expression.size > 1
+        && hasDefaultValue(expression)
+
+    private fun hasDefaultValue(expression: Any?) = expression is List<*> && expression.size > 1
+
+    private fun List<String>.isMissingIn(data: Any?): Boolean {
+        var current = data
+        forEach { key ->
+            current = when (val node = current) {
+                is Map<*, *> -> if (node.containsKey(key)) node[key] else return true
+                is List<*> -> {
+                    val index = key.toIntOrNull()
+                    if (index == null || index !in node.indices) return true
+                    node[index]
+                }
+                else -> return true
+            }
+        }
+        return false
+    }
 
     private tailrec fun getRecursive(indexes: List<String>, data: List<Any?>): Any? = indexes.firstOrNull()?.apply {
         val indexedData = data.getOrNull(intOrZero)
@@ -2,8 +2,9 @@ package io.github.ayushmaanbhav.jsonLogic.operations.numeric
 
 import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
 import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.spreadSingleList
 
 internal object Max : StandardLogicOperation, BigDecimalTypeSensitiveOperation {
     override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
-        bigDecimalResultOrNull(config.mathContext, expression) { it.maxOrNull() }
+        bigDecimalResultOrNull(config.mathContext, expression.spreadSingleList()) { it.maxOrNull() }
 }
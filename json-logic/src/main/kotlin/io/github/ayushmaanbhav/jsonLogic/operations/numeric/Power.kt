@@ -0,0 +1,45 @@
+package io.github.ayushmaanbhav.jsonLogic.operations.numeric
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.operations.numeric.unwrap.LenientUnwrapStrategy
+import io.github.ayushmaanbhav.jsonLogic.utils.setScale
+import java.math.BigDecimal
+
+/**
+ * integral exponents are computed exactly (negative ones as the reciprocal), fractional exponents
+ * fall back to double precision, results that are not real numbers (e.g. 0 ^ -1, -8 ^ 0.5) are null,
+ * as are exact results which would need more than MAX_RESULT_DIGITS digits (e.g. 10 ^ 999999999)
+ */
+internal object Power : StandardLogicOperation, LenientUnwrapStrategy {
+    private const val MAX_RESULT_DIGITS = 10_000L
+
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?) =
+        unwrapValueAsBigDecimal(config, expression).takeIf { it.size >= 2 }?.let {
+            val base = it.first()
+            val exponent = it[1]
+            if (base != null && exponent != null) {
+                power(config, base.stripTrailingZeros(), exponent)?.setScale(config.mathContext)
+            } else null
+        }
+
+    private fun power(config: StandardLogicOperationConfig, base: BigDecimal, exponent: BigDecimal): BigDecimal? {
+        val integralExponent = runCatching { exponent.intValueExact() }.getOrNull()
+        return when {
+            integralExponent == null -> Math.pow(base.toDouble(), exponent.toDouble())
+                .takeIf { it.isFinite() }?.toBigDecimal()
+            exceedsMaxDigits(base, integralExponent) -> null
+            integralExponent >= 0 -> base.pow(integralExponent)
+            BigDecimal.ZERO.compareTo(base) == 0 -> null
+            else -> BigDecimal.ONE.divide(
+                base.pow(-integralExponent), config.mathContext.scale, config.mathContext.roundingMode
+            )
+        }
+    }
+
+    // upper bound of the digits of base ^ exponent, counting the integral and the fractional digits,
+    // powers of 0 and 1 stay a single digit
+    private fun exceedsMaxDigits(base: BigDecimal, exponent: Int): Boolean =
+        base.signum() != 0 && base.abs().compareTo(BigDecimal.ONE) != 0
+            && (base.precision().toLong() + Math.abs(base.scale().toLong())) * Math.abs(exponent.toLong()) > MAX_RESULT_DIGITS
+}
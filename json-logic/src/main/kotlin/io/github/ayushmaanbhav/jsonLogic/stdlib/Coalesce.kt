@@ -0,0 +1,22 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib
+
+import io.github.ayushmaanbhav.jsonLogic.api.LogicEvaluator
+import io.github.ayushmaanbhav.jsonLogic.api.operation.FunctionalLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+
+/**
+ * returns the first argument that is not null, arguments are evaluated lazily
+ * so the ones after the first non null value are never evaluated
+ */
+object Coalesce : FunctionalLogicOperation {
+    override fun evaluateLogic(expression: Any?, data: Any?, evaluator: LogicEvaluator): Any? =
+        expression.asList.asSequence()
+            .map { evaluate(it, data, evaluator) }
+            .firstOrNull { it != null }
+
+    @Suppress("UNCHECKED_CAST")
+    private fun evaluate(argument: Any?, data: Any?, evaluator: LogicEvaluator): Any? = when (argument) {
+        is Map<*, *> -> evaluator.evaluateLogic(argument as Map<String, Any?>, data)
+        else -> argument
+    }
+}
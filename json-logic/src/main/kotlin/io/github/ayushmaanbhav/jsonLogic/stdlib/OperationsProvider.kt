@@ -4,20 +4,38 @@ import io.github.ayushmaanbhav.jsonLogic.api.operation.FunctionalLogicOperation
 import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
 import io.github.ayushmaanbhav.jsonLogic.stdlib.array.Distinct
 import io.github.ayushmaanbhav.jsonLogic.stdlib.array.Find
+import io.github.ayushmaanbhav.jsonLogic.stdlib.array.Flatten
 import io.github.ayushmaanbhav.jsonLogic.stdlib.array.JoinToString
 import io.github.ayushmaanbhav.jsonLogic.stdlib.array.Size
+import io.github.ayushmaanbhav.jsonLogic.stdlib.array.Slice
 import io.github.ayushmaanbhav.jsonLogic.stdlib.array.Sort
 import io.github.ayushmaanbhav.jsonLogic.stdlib.encoding.Encode
 import io.github.ayushmaanbhav.jsonLogic.stdlib.format.DecimalFormat
+import io.github.ayushmaanbhav.jsonLogic.stdlib.format.Format
+import io.github.ayushmaanbhav.jsonLogic.stdlib.math.Abs
+import io.github.ayushmaanbhav.jsonLogic.stdlib.math.Ceil
+import io.github.ayushmaanbhav.jsonLogic.stdlib.math.Floor
+import io.github.ayushmaanbhav.jsonLogic.stdlib.math.Round
+import io.github.ayushmaanbhav.jsonLogic.stdlib.math.Sign
+import io.github.ayushmaanbhav.jsonLogic.stdlib.math.Sqrt
+import io.github.ayushmaanbhav.jsonLogic.stdlib.obj.Entries
+import io.github.ayushmaanbhav.jsonLogic.stdlib.obj.Get
+import io.github.ayushmaanbhav.jsonLogic.stdlib.obj.Keys
+import io.github.ayushmaanbhav.jsonLogic.stdlib.obj.Values
 import io.github.ayushmaanbhav.jsonLogic.stdlib.string.Capitalize
 import io.github.ayushmaanbhav.jsonLogic.stdlib.string.IsBlank
 import io.github.ayushmaanbhav.jsonLogic.stdlib.string.Length
 import io.github.ayushmaanbhav.jsonLogic.stdlib.string.Lowercase
 import io.github.ayushmaanbhav.jsonLogic.stdlib.string.Match
 import io.github.ayushmaanbhav.jsonLogic.stdlib.string.Replace
+import io.github.ayushmaanbhav.jsonLogic.stdlib.string.Split
 import io.github.ayushmaanbhav.jsonLogic.stdlib.string.ToArray
 import io.github.ayushmaanbhav.jsonLogic.stdlib.string.Trim
 import io.github.ayushmaanbhav.jsonLogic.stdlib.string.Uppercase
+import io.github.ayushmaanbhav.jsonLogic.stdlib.time.DateAdd
+import io.github.ayushmaanbhav.jsonLogic.stdlib.time.DateDiff
+import io.github.ayushmaanbhav.jsonLogic.stdlib.time.DateParse
+import io.github.ayushmaanbhav.jsonLogic.stdlib.time.Now
 
 object OperationsProvider {
     val standardOperations: Map<String, StandardLogicOperation> = mutableMapOf(
@@ -27,20 +45,43 @@ object OperationsProvider {
         "length" to Length,
         "lowercase" to Lowercase,
         "replace" to Replace,
+        "split" to Split,
         "uppercase" to Uppercase,
         "toArray" to ToArray,
         "decimalFormat" to DecimalFormat,
+        "format" to Format,
         "encode" to Encode,
         "match" to Match,
 
+        // math
+        "abs" to Abs,
+        "sign" to Sign,
+        "round" to Round,
+        "floor" to Floor,
+        "ceil" to Ceil,
+        "sqrt" to Sqrt,
+
         // time
         "currentTime" to CurrentTimeMillis,
+        "now" to Now,
+        "dateDiff" to DateDiff,
+        "dateAdd" to DateAdd,
+        "dateParse" to DateParse,
 
         // array
         "size" to Size,
         "sort" to Sort,
         "distinct" to Distinct,
+        "unique" to Distinct,
         "joinToString" to JoinToString,
+        "slice" to Slice,
+        "flatten" to Flatten,
+
+        // object
+        "keys" to Keys,
+        "values" to Values,
+        "entries" to Entries,
+        "get" to Get,
 
         "drop" to Drop,
         "reverse" to Reverse,
@@ -49,5 +90,6 @@ object OperationsProvider {
 
     val functionalOperations: Map<String, FunctionalLogicOperation> = mutableMapOf(
         "find" to Find,
+        "coalesce" to Coalesce,
     )
 }
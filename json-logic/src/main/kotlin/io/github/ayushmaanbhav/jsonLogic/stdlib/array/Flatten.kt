@@ -0,0 +1,25 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.array
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+import io.github.ayushmaanbhav.jsonLogic.utils.secondOrNull
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalOrNull
+
+/**
+ * flattens nested arrays up to the given depth, one level by default, a negative depth flattens completely
+ */
+object Flatten : StandardLogicOperation {
+    private const val DEFAULT_DEPTH = 1
+
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        with(expression.asList) {
+            val depth = secondOrNull()?.let { depth ->
+                depth.toBigDecimalOrNull()?.let { runCatching { it.intValueExact() }.getOrNull() } ?: return null
+            } ?: DEFAULT_DEPTH
+            (firstOrNull() as? List<*>)?.flatten(depth)
+        }
+
+    private fun List<*>.flatten(depth: Int): List<Any?> =
+        if (depth == 0) this else flatMap { if (it is List<*>) it.flatten(depth - 1) else listOf(it) }
+}
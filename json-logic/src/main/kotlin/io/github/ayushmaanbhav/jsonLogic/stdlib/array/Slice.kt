@@ -0,0 +1,38 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.array
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+import io.github.ayushmaanbhav.jsonLogic.utils.secondOrNull
+import io.github.ayushmaanbhav.jsonLogic.utils.thirdOrNull
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalOrNull
+
+/**
+ * elements (or characters) from start up to, not including, end, negative indices count from the end
+ * and indices out of bounds are clamped, end defaults to the size
+ */
+object Slice : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        with(expression.asList) {
+            val source = firstOrNull()
+            val length = when (source) {
+                is List<*> -> source.size
+                is String -> source.length
+                else -> return null
+            }
+            val start = secondOrNull().toIndexOrNull(length) ?: return null
+            val end = if (size > 2) thirdOrNull().toIndexOrNull(length) ?: return null else length
+            when {
+                start >= end -> source.emptySlice()
+                source is List<*> -> source.subList(start, end)
+                else -> (source as String).substring(start, end)
+            }
+        }
+
+    private fun Any?.toIndexOrNull(size: Int): Int? =
+        toBigDecimalOrNull()?.let { runCatching { it.intValueExact() }.getOrNull() }
+            ?.let { if (it < 0) it + size else it }
+            ?.coerceIn(0, size)
+
+    private fun Any.emptySlice(): Any = if (this is String) "" else emptyList<Any?>()
+}
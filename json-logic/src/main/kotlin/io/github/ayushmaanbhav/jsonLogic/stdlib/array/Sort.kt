@@ -5,13 +5,18 @@ import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
 import io.github.ayushmaanbhav.jsonLogic.utils.asBigDecimalList
 import io.github.ayushmaanbhav.jsonLogic.utils.asList
 import io.github.ayushmaanbhav.jsonLogic.utils.secondOrNull
+import io.github.ayushmaanbhav.jsonLogic.utils.thirdOrNull
 
 object Sort : StandardLogicOperation {
     override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
         with(expression.asList) {
             (firstOrNull() as? List<*>)?.let { elementsToSort ->
                 val sortingMode = (secondOrNull() as? String).toSortOrder()
-                elementsToSort.sortByMode(sortingMode)
+                when (val key = thirdOrNull()) {
+                    null -> elementsToSort.sortByMode(sortingMode)
+                    is String -> elementsToSort.sortByKey(key, sortingMode)
+                    else -> null
+                }
             }
         }
 
@@ -28,6 +33,24 @@ object Sort : StandardLogicOperation {
         else -> null
     }
 
+    // objects are ordered (stable) by their value under key, following the same typing rules as plain elements
+    @Suppress("UNCHECKED_CAST")
+    private fun List<Any?>.sortByKey(key: String, sortingMode: SortOrder): Any? {
+        if (containsOnlyElementsOfType<Map<*, *>>().not()) return null
+        val keys = map { (it as Map<*, *>)[key] }
+        val comparableKeys = when {
+            keys.containsOnlyElementsOfType<String>() || keys.containsOnlyElementsOfType<Boolean>() -> keys
+            keys.containsOnlyElementsOfType<Number>() -> keys.asBigDecimalList
+            else -> return null
+        } as List<Comparable<Any>>
+        val comparator = Comparator<Int> { a, b -> comparableKeys[a].compareTo(comparableKeys[b]) }
+        return modeBasedSort(
+            sortingMode = sortingMode,
+            ascSort = { indices.sortedWith(comparator).map(this::get) },
+            descSort = { indices.sortedWith(comparator.reversed()).map(this::get) },
+        )
+    }
+
     private inline fun <reified T> List<Any?>?.containsOnlyElementsOfType() =
         this?.filterIsInstance<T>()?.size == this?.size
 
@@ -26,7 +26,7 @@ internal interface DecimalFormatter {
     private fun String.formatAsFloatingDecimal(
         formattedArgument: String,
         formatFloatingPoint: (String, BigDecimal) -> String
-    ) = if (matches("%[\\d|.]*[f]".toRegex())) {
+    ) = if (matches("%,?[\\d|.]*[f]".toRegex())) {
             formattedArgument.toBigDecimalOrNull()?.let {
                 formatFloatingPoint(this, it)
             }
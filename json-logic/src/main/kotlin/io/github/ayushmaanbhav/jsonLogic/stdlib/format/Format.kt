@@ -0,0 +1,51 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.format
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+import io.github.ayushmaanbhav.jsonLogic.utils.secondOrNull
+import io.github.ayushmaanbhav.jsonLogic.utils.thirdOrNull
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalOrNull
+import java.lang.String.format
+import java.math.BigDecimal
+import java.text.DecimalFormatSymbols
+import java.util.Locale
+
+/**
+ * formats a number with a spec of the form `[0][width][,][.precision](f|%)`, e.g. ".2f", "08.3f", ",.2f", ".0%"
+ * a leading 0 pads with zeros instead of spaces, a comma adds grouping separators, unknown specs result in null
+ * separators follow the locale given as an optional third argument (e.g. "de-DE"), or the configured locale,
+ * unknown locales result in null
+ */
+object Format : StandardLogicOperation, DecimalFormatter {
+    private val SPEC_REGEX = "(0?)(\\d*)(,?)(?:\\.(\\d+))?([f%])".toRegex()
+    private const val DEFAULT_PRECISION = 6
+    private const val PERCENT = "%"
+    private val HUNDRED = BigDecimal(100)
+    private val AVAILABLE_LOCALES by lazy { DecimalFormatSymbols.getAvailableLocales().toSet() - Locale.ROOT }
+
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        with(expression.asList) {
+            val value = firstOrNull().toBigDecimalOrNull()
+            val spec = (secondOrNull() as? String)?.let { SPEC_REGEX.matchEntire(it) }
+            val locale = thirdOrNull()?.let { (it as? String)?.let(::availableLocale) } ?: config.locale.takeIf { thirdOrNull() == null }
+            if (value == null || spec == null || locale == null) null else formatNumber(value, spec.destructured.toList(), locale, data)
+        }
+
+    // translates the spec into a decimal format sequence, percentages count their sign into the width
+    private fun formatNumber(value: BigDecimal, spec: List<String>, locale: Locale, data: Any?): String? {
+        val (zeroPad, width, grouping, precision, type) = spec
+        val suffix = if (type == PERCENT) PERCENT else ""
+        val padWidth = width.toIntOrNull()?.minus(suffix.length)?.takeIf { it > 0 }
+        val formatSequence = "%" + grouping + (padWidth?.let { zeroPad + it } ?: "") +
+            "." + (precision.toIntOrNull() ?: DEFAULT_PRECISION) + "f"
+        val scaled = if (type == PERCENT) value.multiply(HUNDRED) else value
+        return formatDecimal(listOf(formatSequence, scaled.toPlainString()), data) { sequence: String, arg: BigDecimal ->
+            format(locale, sequence, arg)
+        }?.plus(suffix)
+    }
+
+    // Locale.forLanguageTag falls back to the root locale for tags it does not know
+    private fun availableLocale(languageTag: String): Locale? =
+        Locale.forLanguageTag(languageTag).takeIf(AVAILABLE_LOCALES::contains)
+}
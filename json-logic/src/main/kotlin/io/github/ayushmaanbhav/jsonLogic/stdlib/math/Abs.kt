@@ -0,0 +1,11 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.math
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalOrNull
+
+object Abs : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        expression.asList.firstOrNull().toBigDecimalOrNull(config.mathContext)?.abs()
+}
@@ -0,0 +1,24 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.math
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+import io.github.ayushmaanbhav.jsonLogic.utils.secondOrNull
+import io.github.ayushmaanbhav.jsonLogic.utils.setScale
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalOrNull
+import java.math.RoundingMode
+
+/**
+ * rounds half up to the given number of decimal places, or to an integer when the precision is omitted
+ */
+object Round : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        with(expression.asList) {
+            val value = firstOrNull().toBigDecimalOrNull(config.mathContext)
+            val precision = if (size < 2) 0 else secondOrNull().toBigDecimalOrNull()?.let {
+                runCatching { it.intValueExact() }.getOrNull()
+            }
+            if (value == null || precision == null) null
+            else value.setScale(precision, RoundingMode.HALF_UP).setScale(config.mathContext)
+        }
+}
@@ -0,0 +1,16 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.math
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+import io.github.ayushmaanbhav.jsonLogic.utils.setScale
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalOrNull
+import java.math.BigDecimal
+
+// -1, 0 or 1 depending on the sign of the number
+object Sign : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        expression.asList.firstOrNull().toBigDecimalOrNull(config.mathContext)?.let {
+            BigDecimal(it.signum()).setScale(config.mathContext)
+        }
+}
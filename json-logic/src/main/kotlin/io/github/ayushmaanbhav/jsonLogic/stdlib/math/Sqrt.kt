@@ -0,0 +1,20 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.math
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+import io.github.ayushmaanbhav.jsonLogic.utils.setScale
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalOrNull
+import java.math.MathContext
+
+/**
+ * square root computed to the configured scale, negative values result in null
+ */
+object Sqrt : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        expression.asList.firstOrNull().toBigDecimalOrNull(config.mathContext)?.takeIf { it.signum() >= 0 }?.let {
+            val integerDigits = it.toBigInteger().toString().length
+            it.sqrt(MathContext(config.mathContext.scale + integerDigits, config.mathContext.roundingMode))
+                .setScale(config.mathContext)
+        }
+}
@@ -0,0 +1,16 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.obj
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+
+/**
+ * lists the entries of an object as {"key": .., "value": ..} objects, so they can be used with map/filter
+ */
+object Entries : StandardLogicOperation {
+    const val KEY = "key"
+    const val VALUE = "value"
+
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        (expression.asList.firstOrNull() as? Map<*, *>)?.map { (key, value) -> mapOf(KEY to key, VALUE to value) }
+}
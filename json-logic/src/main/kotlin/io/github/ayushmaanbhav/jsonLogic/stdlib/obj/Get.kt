@@ -0,0 +1,23 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.obj
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+import io.github.ayushmaanbhav.jsonLogic.utils.secondOrNull
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalOrNull
+
+/**
+ * dynamic access into a computed object by key, or into an array by index, unlike var it does not read from data
+ */
+object Get : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        with(expression.asList) {
+            when (val source = firstOrNull()) {
+                is Map<*, *> -> secondOrNull()?.let { source[it.toString()] }
+                is List<*> -> secondOrNull().toBigDecimalOrNull()
+                    ?.let { runCatching { it.intValueExact() }.getOrNull() }
+                    ?.let { source.getOrNull(it) }
+                else -> null
+            }
+        }
+}
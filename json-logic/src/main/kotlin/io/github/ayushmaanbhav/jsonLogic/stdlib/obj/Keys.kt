@@ -0,0 +1,10 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.obj
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+
+object Keys : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        (expression.asList.firstOrNull() as? Map<*, *>)?.keys?.toList()
+}
@@ -0,0 +1,15 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.string
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+import io.github.ayushmaanbhav.jsonLogic.utils.secondOrNull
+
+object Split : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        with(expression.asList) {
+            val text = firstOrNull() as? String
+            val delimiter = (secondOrNull() as? String)?.takeIf { it.isNotEmpty() }
+            if (size != 2 || text == null || delimiter == null) null else text.split(delimiter)
+        }
+}
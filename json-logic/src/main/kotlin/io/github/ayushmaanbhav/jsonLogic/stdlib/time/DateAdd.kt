@@ -0,0 +1,25 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.time
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+import io.github.ayushmaanbhav.jsonLogic.utils.secondOrNull
+import io.github.ayushmaanbhav.jsonLogic.utils.thirdOrNull
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalOrNull
+import java.time.Duration
+import java.time.temporal.ChronoUnit
+
+/**
+ * adds a whole amount of the given unit (seconds by default) to a date and returns it as an ISO-8601 UTC string,
+ * days are fixed 24 hour periods, invalid amounts or units result in null, invalid or out of range dates are an error
+ */
+object DateAdd : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        with(expression.asList) {
+            val date = firstOrNull().toInstantOrNull()
+            val amount = secondOrNull().toBigDecimalOrNull()?.let { runCatching { it.longValueExact() }.getOrNull() }
+            val unit = if (size < 3) ChronoUnit.SECONDS else thirdOrNull().toChronoUnitOrNull()
+            if (date == null || amount == null || unit == null) null
+            else withinDateRange(firstOrNull()) { date.plus(Duration.of(amount, unit)).toString() }
+        }
+}
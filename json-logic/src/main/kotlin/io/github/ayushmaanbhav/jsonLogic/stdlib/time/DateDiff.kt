@@ -0,0 +1,23 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.time
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+import io.github.ayushmaanbhav.jsonLogic.utils.secondOrNull
+import io.github.ayushmaanbhav.jsonLogic.utils.thirdOrNull
+import java.time.temporal.ChronoUnit
+
+/**
+ * whole units from the second date to the first, i.e. first - second, in seconds unless a unit is given
+ * (millis, seconds, minutes, hours or days), invalid units result in null, invalid dates or overflows are an error
+ */
+object DateDiff : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        with(expression.asList) {
+            val end = firstOrNull().toInstantOrNull()
+            val start = secondOrNull().toInstantOrNull()
+            val unit = if (size < 3) ChronoUnit.SECONDS else thirdOrNull().toChronoUnitOrNull()
+            if (end == null || start == null || unit == null) null
+            else withinDateRange("${firstOrNull()} - ${secondOrNull()}") { unit.between(start, end) }
+        }
+}
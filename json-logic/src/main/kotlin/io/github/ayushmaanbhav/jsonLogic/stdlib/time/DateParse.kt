@@ -0,0 +1,13 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.time
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.utils.asList
+
+/**
+ * parses an ISO-8601 date time into epoch milliseconds, same as currentTime, invalid or out of range dates are an error
+ */
+object DateParse : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any? =
+        expression.asList.singleOrNull().let { date -> withinDateRange(date) { date.toInstantOrNull()?.toEpochMilli() } }
+}
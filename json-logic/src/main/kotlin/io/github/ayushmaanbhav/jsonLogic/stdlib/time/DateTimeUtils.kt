@@ -0,0 +1,43 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.time
+
+import io.github.ayushmaanbhav.jsonLogic.api.InvalidDateException
+import java.time.DateTimeException
+import java.time.Instant
+import java.time.LocalDate
+import java.time.LocalDateTime
+import java.time.OffsetDateTime
+import java.time.ZoneOffset
+import java.time.temporal.ChronoUnit
+
+/**
+ * parses an ISO-8601 date time with an offset, e.g. "2024-01-01T00:00:00Z" or "2024-01-01T05:30:00+05:30",
+ * date times and dates without an offset are taken as UTC, null results in null and anything else is an invalid date
+ */
+internal fun Any?.toInstantOrNull(): Instant? = this?.let { value ->
+    (value as? String)?.let { text ->
+        runCatching { OffsetDateTime.parse(text).toInstant() }
+            .recoverCatching { LocalDateTime.parse(text).toInstant(ZoneOffset.UTC) }
+            .recoverCatching { LocalDate.parse(text).atStartOfDay().toInstant(ZoneOffset.UTC) }
+            .getOrNull()
+    } ?: throw InvalidDateException(value.toString())
+}
+
+/**
+ * results which overflow or fall out of the supported range are reported as an invalid date
+ */
+internal fun <T> withinDateRange(value: Any?, calculation: () -> T): T = try {
+    calculation()
+} catch (e: ArithmeticException) {
+    throw InvalidDateException(value.toString())
+} catch (e: DateTimeException) {
+    throw InvalidDateException(value.toString())
+}
+
+internal fun Any?.toChronoUnitOrNull(): ChronoUnit? = when (this) {
+    "millis" -> ChronoUnit.MILLIS
+    "seconds" -> ChronoUnit.SECONDS
+    "minutes" -> ChronoUnit.MINUTES
+    "hours" -> ChronoUnit.HOURS
+    "days" -> ChronoUnit.DAYS
+    else -> null
+}
@@ -0,0 +1,12 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.time
+
+import io.github.ayushmaanbhav.jsonLogic.api.operation.StandardLogicOperation
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import java.time.Instant
+
+/**
+ * current time as an ISO-8601 UTC string, e.g. "2024-01-01T00:00:00.123Z"
+ */
+object Now : StandardLogicOperation {
+    override fun evaluateLogic(config: StandardLogicOperationConfig, expression: Any?, data: Any?): Any = Instant.now().toString()
+}
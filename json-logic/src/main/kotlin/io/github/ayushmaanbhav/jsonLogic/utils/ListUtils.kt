@@ -3,5 +3,8 @@ package io.github.ayushmaanbhav.jsonLogic.utils
 fun <T>List<T>.secondOrNull() = getOrNull(1)
 fun <T>List<T>.thirdOrNull() = getOrNull(2)
 
+// a single array argument is treated as the argument list, eg. {"max": [[1, 2, 3]]}
+fun Any?.spreadSingleList(): Any? = ((this as? List<*>)?.singleOrNull() as? List<*>) ?: this
+
 @Suppress("UNCHECKED_CAST")
 fun List<Any?>.getMappingOperationOrNull() = secondOrNull().takeIf { it.isExpression() } as? Map<String, Any>
@@ -43,6 +43,24 @@ class CommonJsonLogicEngineTest : BehaviorSpec({
         }
     }
 
+    given("An evaluation on an interrupted thread") {
+        val logicExpression = mapOf("+" to listOf(1, mapOf("var" to "int")))
+        val data = mapOf("int" to 2)
+
+        `when`("on evaluation") {
+            Thread.currentThread().interrupt()
+            val result = try {
+                logicEngine.evaluate(logicExpression, data)
+            } finally {
+                Thread.interrupted()
+            }
+
+            then("returns interrupted failure result") {
+                result shouldBe JsonLogicResult.Failure.Interrupted
+            }
+        }
+    }
+
     given("A not null result expression") {
         val logicExpression = mapOf("var" to "int")
         val data = mapOf("int" to 2)
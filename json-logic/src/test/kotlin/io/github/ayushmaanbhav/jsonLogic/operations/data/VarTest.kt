@@ -2,6 +2,8 @@ package io.github.ayushmaanbhav.jsonLogic.operations.data
 
 import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
 import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.config.MathContext
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
 import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
 import io.github.ayushmaanbhav.jsonLogic.valueShouldBe
 import io.kotest.core.spec.style.FunSpec
@@ -222,4 +224,67 @@ class VarTest : FunSpec({
         // then
         evaluationResult valueShouldBe testInput.result
     }
+
+    context("with error on missing variable") {
+        val strictLogicEngine = JsonLogicEngine.Builder()
+            .addStandardConfig(StandardLogicOperationConfig(MathContext.DEFAULT, '.', errorOnMissingVariable = true))
+            .build()
+
+        withData(
+            nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+            ts = listOf(
+                TestInput(
+                    expression = mapOf("var" to "a"),
+                    data = mapOf("a" to "apple"),
+                    result = JsonLogicResult.Success("apple")
+                ),
+                TestInput(
+                    expression = mapOf("var" to "a.b"),
+                    data = mapOf("a" to mapOf("b" to "banana")),
+                    result = JsonLogicResult.Success("banana")
+                ),
+                TestInput(
+                    expression = mapOf("var" to "b"),
+                    data = mapOf("a" to 1),
+                    result = JsonLogicResult.Failure.MissingVariable("b")
+                ),
+                TestInput(
+                    expression = mapOf("var" to "a.c"),
+                    data = mapOf("a" to mapOf("b" to "banana")),
+                    result = JsonLogicResult.Failure.MissingVariable("a.c")
+                ),
+                TestInput(
+                    expression = mapOf("var" to "3"),
+                    data = listOf("apple", "banana"),
+                    result = JsonLogicResult.Failure.MissingVariable("3")
+                ),
+                TestInput(
+                    expression = mapOf("var" to "a"),
+                    result = JsonLogicResult.Failure.MissingVariable("a")
+                ),
+                TestInput(
+                    expression = mapOf("var" to listOf("b", "default")),
+                    data = mapOf("a" to 1),
+                    result = JsonLogicResult.Success("default")
+                ),
+                TestInput(
+                    expression = mapOf("var" to "a"),
+                    data = mapOf("a" to null),
+                    result = JsonLogicResult.Failure.NullResult
+                ),
+                TestInput(
+                    expression = mapOf("missing" to listOf("a", "b")),
+                    data = mapOf("a" to 1),
+                    result = JsonLogicResult.Success(listOf("b"))
+                ),
+            )
+            // given
+        ) { testInput: TestInput ->
+            // when
+            val evaluationResult = strictLogicEngine.evaluate(testInput.expression, testInput.data)
+
+            // then
+            evaluationResult valueShouldBe testInput.result
+        }
+    }
 })
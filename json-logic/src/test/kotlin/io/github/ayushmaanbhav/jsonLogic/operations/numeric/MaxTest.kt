@@ -54,6 +54,23 @@ class MaxTest : FunSpec({
                 expression = mapOf("max" to listOf(1, "banana", listOf(1, 2))),
                 result = JsonLogicResult.Failure.NullResult
             ),
+            TestInput(
+                expression = mapOf("max" to listOf(listOf(1, 2, 3))),
+                result = JsonLogicResult.Success(3.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("max" to listOf(mapOf("var" to "values"))),
+                data = mapOf("values" to listOf(4, "7", 5)),
+                result = JsonLogicResult.Success(7.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("max" to listOf(emptyList<Int>())),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("max" to listOf(listOf(1, "banana"))),
+                result = JsonLogicResult.Failure.NullResult
+            ),
         )
         // given
     ) { testInput: TestInput ->
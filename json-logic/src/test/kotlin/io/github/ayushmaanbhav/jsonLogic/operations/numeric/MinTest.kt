@@ -58,6 +58,14 @@ class MinTest : FunSpec({
                 expression = mapOf("min" to listOf(1, "banana", listOf(1, 2))),
                 result = JsonLogicResult.Failure.NullResult
             ),
+            TestInput(
+                expression = mapOf("min" to listOf(listOf(3, 1, 2))),
+                result = JsonLogicResult.Success(1.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("min" to listOf(emptyList<Int>())),
+                result = JsonLogicResult.Failure.NullResult
+            ),
         )
         // given
     ) { testInput: TestInput ->
@@ -0,0 +1,75 @@
+package io.github.ayushmaanbhav.jsonLogic.operations.numeric
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalDefaultContext
+import io.github.ayushmaanbhav.jsonLogic.valueShouldBe
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+
+class PowerTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("pow" to listOf(2, 10)),
+                result = JsonLogicResult.Success(1024.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("pow" to listOf(mapOf("+" to listOf(1, mapOf("var" to "r"))), mapOf("var" to "n"))),
+                data = mapOf("r" to 0.1, "n" to 2),
+                result = JsonLogicResult.Success(1.21.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("pow" to listOf(16, 0.5)),
+                result = JsonLogicResult.Success(4.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("pow" to listOf(2, -2)),
+                result = JsonLogicResult.Success(0.25.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("pow" to listOf(5, 0)),
+                result = JsonLogicResult.Success(1.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("pow" to listOf(0, -1)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("pow" to listOf(-8, 0.5)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("pow" to listOf(10, 999999999)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("pow" to listOf(1.0, 999999999)),
+                result = JsonLogicResult.Success(1.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("pow" to listOf(10, -999999999)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("pow" to listOf(2)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("pow" to listOf("a", 2)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult valueShouldBe testInput.result
+    }
+})
@@ -0,0 +1,59 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+import io.kotest.matchers.shouldBe
+
+class CoalesceTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().addFunctionalOperation("coalesce", Coalesce).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("coalesce" to listOf(mapOf("var" to "primary"), mapOf("var" to "secondary"), 0)),
+                data = mapOf("secondary" to "b"),
+                result = JsonLogicResult.Success("b")
+            ),
+            TestInput(
+                expression = mapOf("coalesce" to listOf(mapOf("var" to "primary"), mapOf("var" to "secondary"), 0)),
+                data = mapOf("primary" to "a", "secondary" to "b"),
+                result = JsonLogicResult.Success("a")
+            ),
+            TestInput(
+                expression = mapOf("coalesce" to listOf(mapOf("var" to "primary"), 0)),
+                result = JsonLogicResult.Success(0)
+            ),
+            TestInput(
+                expression = mapOf("coalesce" to listOf(false, 1)),
+                result = JsonLogicResult.Success(false)
+            ),
+            // later arguments are not evaluated once a value is found, an unknown operation would fail otherwise
+            TestInput(
+                expression = mapOf("coalesce" to listOf(1, mapOf("unknown" to emptyList<Any>()))),
+                result = JsonLogicResult.Success(1)
+            ),
+            TestInput(
+                expression = mapOf("coalesce" to listOf(null, mapOf("unknown" to emptyList<Any>()))),
+                result = JsonLogicResult.Failure.MissingOperation
+            ),
+            TestInput(
+                expression = mapOf("coalesce" to listOf(null, mapOf("var" to "missing"))),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("coalesce" to emptyList<Any>()),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult shouldBe testInput.result
+    }
+})
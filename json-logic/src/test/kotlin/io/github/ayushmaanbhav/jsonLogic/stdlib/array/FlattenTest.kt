@@ -0,0 +1,49 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.array
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+import io.kotest.matchers.shouldBe
+
+class FlattenTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation("flatten", Flatten).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("flatten" to listOf(mapOf("var" to "nested"))),
+                data = mapOf("nested" to listOf(1, listOf(2, listOf(3, listOf(4))))),
+                result = JsonLogicResult.Success(listOf(1, 2, listOf(3, listOf(4))))
+            ),
+            TestInput(
+                expression = mapOf("flatten" to listOf(mapOf("var" to "nested"), 2)),
+                data = mapOf("nested" to listOf(1, listOf(2, listOf(3, listOf(4))))),
+                result = JsonLogicResult.Success(listOf(1, 2, 3, listOf(4)))
+            ),
+            TestInput(
+                expression = mapOf("flatten" to listOf(mapOf("var" to "nested"), -1)),
+                data = mapOf("nested" to listOf(1, listOf(2, listOf(3, listOf(4))))),
+                result = JsonLogicResult.Success(listOf(1, 2, 3, 4))
+            ),
+            TestInput(
+                expression = mapOf("flatten" to listOf(mapOf("var" to "nested"), 0)),
+                data = mapOf("nested" to listOf(1, listOf(2))),
+                result = JsonLogicResult.Success(listOf(1, listOf(2)))
+            ),
+            TestInput(
+                expression = mapOf("flatten" to listOf("banana")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult shouldBe testInput.result
+    }
+})
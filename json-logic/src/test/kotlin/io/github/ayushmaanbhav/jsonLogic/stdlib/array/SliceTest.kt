@@ -0,0 +1,63 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.array
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+import io.kotest.matchers.shouldBe
+
+class SliceTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation("slice", Slice).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("slice" to listOf(mapOf("var" to "items"), 0, 2)),
+                data = mapOf("items" to listOf("a", "b", "c", "d")),
+                result = JsonLogicResult.Success(listOf("a", "b"))
+            ),
+            TestInput(
+                expression = mapOf("slice" to listOf(mapOf("var" to "items"), -2)),
+                data = mapOf("items" to listOf("a", "b", "c", "d")),
+                result = JsonLogicResult.Success(listOf("c", "d"))
+            ),
+            TestInput(
+                expression = mapOf("slice" to listOf(mapOf("var" to "items"), 1, -1)),
+                data = mapOf("items" to listOf("a", "b", "c", "d")),
+                result = JsonLogicResult.Success(listOf("b", "c"))
+            ),
+            TestInput(
+                expression = mapOf("slice" to listOf(mapOf("var" to "items"), -10, 10)),
+                data = mapOf("items" to listOf("a", "b")),
+                result = JsonLogicResult.Success(listOf("a", "b"))
+            ),
+            TestInput(
+                expression = mapOf("slice" to listOf(mapOf("var" to "items"), 3, 1)),
+                data = mapOf("items" to listOf("a", "b", "c", "d")),
+                result = JsonLogicResult.Success(emptyList<String>())
+            ),
+            TestInput(
+                expression = mapOf("slice" to listOf("banana", 1, 3)),
+                result = JsonLogicResult.Success("an")
+            ),
+            TestInput(
+                expression = mapOf("slice" to listOf(mapOf("var" to "items"), "a")),
+                data = mapOf("items" to listOf("a", "b")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("slice" to listOf(5, 1)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult shouldBe testInput.result
+    }
+})
@@ -168,6 +168,33 @@ class SortTest : FunSpec({
                 expression = mapOf(operatorName to listOf(listOf(true, false), "desc")),
                 result = Success(listOf(true, false))
             ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(mapOf("var" to "items"), "asc", "price")),
+                data = mapOf(
+                    "items" to listOf(
+                        mapOf("name" to "b", "price" to 2), mapOf("name" to "a", "price" to 1.5), mapOf("name" to "c", "price" to 2)
+                    )
+                ),
+                result = Success(
+                    listOf(
+                        mapOf("name" to "a", "price" to 1.5), mapOf("name" to "b", "price" to 2), mapOf("name" to "c", "price" to 2)
+                    )
+                )
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(mapOf("var" to "items"), "desc", "name")),
+                data = mapOf("items" to listOf(mapOf("name" to "apple"), mapOf("name" to "pear"), mapOf("name" to "fig"))),
+                result = Success(listOf(mapOf("name" to "pear"), mapOf("name" to "fig"), mapOf("name" to "apple")))
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(mapOf("var" to "items"), "asc", "price")),
+                data = mapOf("items" to listOf(mapOf("price" to 1), mapOf("price" to "2"))),
+                result = Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(listOf(1, 2), "asc", "price")),
+                result = Failure.NullResult
+            ),
             TestInput(
                 expression = mapOf(
                     operatorName to listOf(
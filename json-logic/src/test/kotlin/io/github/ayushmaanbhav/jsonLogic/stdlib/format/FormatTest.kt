@@ -0,0 +1,111 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.format
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.config.MathContext
+import io.github.ayushmaanbhav.jsonLogic.config.StandardLogicOperationConfig
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+import io.kotest.matchers.shouldBe
+import java.util.Locale
+
+class FormatTest : FunSpec({
+    val operatorName = "format"
+    val logicEngine = JsonLogicEngine.Builder()
+        .addStandardOperation(operatorName, Format)
+        .build()
+
+    withData(
+        nameFn = { input -> "Should evaluate format operation ${input.expression} into ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf(operatorName to listOf(mapOf("var" to "amount"), ".2f")),
+                data = mapOf("amount" to 1234.5),
+                result = JsonLogicResult.Success("1234.50")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(2.675, ".2f")),
+                result = JsonLogicResult.Success("2.68")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(0.256, ".0%")),
+                result = JsonLogicResult.Success("26%")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(0.5, ".1%")),
+                result = JsonLogicResult.Success("50.0%")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(3.5, "08.2f")),
+                result = JsonLogicResult.Success("00003.50")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(-3.5, "08.2f")),
+                result = JsonLogicResult.Success("-0003.50")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(3.5, "6.1f")),
+                result = JsonLogicResult.Success("   3.5")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(1234.5, ",.2f")),
+                result = JsonLogicResult.Success("1,234.50")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(1234.5, ",.2f", "de-DE")),
+                result = JsonLogicResult.Success("1.234,50")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(0.125, ".1%", "de-DE")),
+                result = JsonLogicResult.Success("12,5%")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(-1234567.891, ",.1f")),
+                result = JsonLogicResult.Success("-1,234,567.9")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(0.5, "07.1%")),
+                result = JsonLogicResult.Success("0050.0%")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(1234.5, ",.2f", "xx-YY")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(1234.5, ",.2f", "not a locale")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(3.5, ".2x")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("abc", ".2f")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult shouldBe testInput.result
+    }
+
+    test("Should format with the configured locale when no locale is given") {
+        // given
+        val germanLogicEngine = JsonLogicEngine.Builder()
+            .addStandardOperation(operatorName, Format)
+            .addStandardConfig(StandardLogicOperationConfig(MathContext.DEFAULT, '.', Locale.GERMANY))
+            .build()
+        val expression = mapOf(operatorName to listOf(1234.5, ",.2f"))
+
+        // when
+        val evaluationResult = germanLogicEngine.evaluate(expression, null)
+
+        // then
+        evaluationResult shouldBe JsonLogicResult.Success("1.234,50")
+    }
+})
@@ -0,0 +1,42 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.math
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalDefaultContext
+import io.github.ayushmaanbhav.jsonLogic.valueShouldBe
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+
+class AbsTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation("abs", Abs).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("abs" to listOf(-3.5)),
+                result = JsonLogicResult.Success(3.5.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("abs" to listOf(mapOf("-" to listOf(mapOf("+" to listOf(1, 2)))))),
+                result = JsonLogicResult.Success(3.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("abs" to "4"),
+                result = JsonLogicResult.Success(4.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("abs" to listOf("banana")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult valueShouldBe testInput.result
+    }
+})
@@ -0,0 +1,42 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.math
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalDefaultContext
+import io.github.ayushmaanbhav.jsonLogic.valueShouldBe
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+
+class FloorTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation("floor", Floor).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("floor" to listOf(3.7)),
+                result = JsonLogicResult.Success(3.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("floor" to listOf(-3.2)),
+                result = JsonLogicResult.Success(-4.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("floor" to "5"),
+                result = JsonLogicResult.Success(5.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("floor" to listOf("banana")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult valueShouldBe testInput.result
+    }
+})
@@ -0,0 +1,58 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.math
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalDefaultContext
+import io.github.ayushmaanbhav.jsonLogic.valueShouldBe
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+
+class RoundTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation("round", Round).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("round" to listOf(3.14159, 2)),
+                result = JsonLogicResult.Success(3.14.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("round" to listOf(2.5)),
+                result = JsonLogicResult.Success(3.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("round" to listOf(-2.5)),
+                result = JsonLogicResult.Success(-3.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("round" to listOf(1234.5, -2)),
+                result = JsonLogicResult.Success(1200.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("round" to listOf("2.675", "2")),
+                result = JsonLogicResult.Success(2.68.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("round" to listOf("banana")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("round" to listOf(3.14159, "banana")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("round" to listOf(3.14159, 1.5)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult valueShouldBe testInput.result
+    }
+})
@@ -0,0 +1,43 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.math
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalDefaultContext
+import io.github.ayushmaanbhav.jsonLogic.valueShouldBe
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+
+class SignTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation("sign", Sign).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("sign" to listOf(-3)),
+                result = JsonLogicResult.Success((-1).toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("sign" to listOf(0)),
+                result = JsonLogicResult.Success(0.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("sign" to listOf(mapOf("var" to "x"))),
+                data = mapOf("x" to 0.25),
+                result = JsonLogicResult.Success(1.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("sign" to listOf(null)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult valueShouldBe testInput.result
+    }
+})
@@ -0,0 +1,46 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.math
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.github.ayushmaanbhav.jsonLogic.utils.toBigDecimalDefaultContext
+import io.github.ayushmaanbhav.jsonLogic.valueShouldBe
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+
+class SqrtTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation("sqrt", Sqrt).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("sqrt" to listOf(16)),
+                result = JsonLogicResult.Success(4.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("sqrt" to listOf("2.25")),
+                result = JsonLogicResult.Success(1.5.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("sqrt" to listOf(0)),
+                result = JsonLogicResult.Success(0.toBigDecimalDefaultContext())
+            ),
+            TestInput(
+                expression = mapOf("sqrt" to listOf(-4)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("sqrt" to listOf("banana")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult valueShouldBe testInput.result
+    }
+})
@@ -0,0 +1,34 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.obj
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+import io.kotest.matchers.shouldBe
+
+class EntriesTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation("entries", Entries).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("entries" to listOf(mapOf("var" to "obj"))),
+                data = mapOf("obj" to mapOf("a" to "x", "b" to "y")),
+                result = JsonLogicResult.Success(listOf(mapOf("key" to "a", "value" to "x"), mapOf("key" to "b", "value" to "y")))
+            ),
+            TestInput(
+                expression = mapOf("entries" to listOf("banana")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult shouldBe testInput.result
+    }
+})
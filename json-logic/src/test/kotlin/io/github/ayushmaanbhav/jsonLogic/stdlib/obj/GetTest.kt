@@ -0,0 +1,47 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.obj
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+import io.kotest.matchers.shouldBe
+
+class GetTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation("get", Get).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("get" to listOf(mapOf("var" to "obj"), mapOf("var" to "key"))),
+                data = mapOf("obj" to mapOf("gold" to "x", "silver" to "y"), "key" to "silver"),
+                result = JsonLogicResult.Success("y")
+            ),
+            TestInput(
+                expression = mapOf("get" to listOf(mapOf("var" to "obj"), "bronze")),
+                data = mapOf("obj" to mapOf("gold" to "x")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("get" to listOf(listOf("a", "b", "c"), 1)),
+                result = JsonLogicResult.Success("b")
+            ),
+            TestInput(
+                expression = mapOf("get" to listOf(listOf("a", "b", "c"), 5)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("get" to listOf("banana", 1)),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult shouldBe testInput.result
+    }
+})
@@ -0,0 +1,43 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.obj
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+import io.kotest.matchers.shouldBe
+
+class KeysTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation("keys", Keys).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf("keys" to listOf(mapOf("var" to "obj"))),
+                data = mapOf("obj" to mapOf("a" to 1, "b" to 2)),
+                result = JsonLogicResult.Success(listOf("a", "b"))
+            ),
+            TestInput(
+                expression = mapOf("keys" to listOf(mapOf("var" to "obj"))),
+                data = mapOf("obj" to emptyMap<String, Any>()),
+                result = JsonLogicResult.Success(emptyList<String>())
+            ),
+            TestInput(
+                expression = mapOf("keys" to listOf(listOf(1, 2))),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf("keys" to "banana"),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult shouldBe testInput.result
+    }
+})
@@ -0,0 +1,64 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.string
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult.Failure
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult.Success
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+import io.kotest.matchers.shouldBe
+
+class SplitTest : FunSpec({
+    val operatorName = "split"
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation(operatorName, Split).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf(operatorName to listOf("a,b", ",")),
+                result = Success(listOf("a", "b"))
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("a, b, c", ", ")),
+                result = Success(listOf("a", "b", "c"))
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("a,,b,", ",")),
+                result = Success(listOf("a", "", "b", ""))
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("abc", ",")),
+                result = Success(listOf("abc"))
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(mapOf("var" to "sku"), "-")),
+                data = mapOf("sku" to "PF-2024-XL"),
+                result = Success(listOf("PF", "2024", "XL"))
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("abc", "")),
+                result = Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(123, ",")),
+                result = Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("a,b")),
+                result = Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("a,b", ",", ",")),
+                result = Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult shouldBe testInput.result
+    }
+})
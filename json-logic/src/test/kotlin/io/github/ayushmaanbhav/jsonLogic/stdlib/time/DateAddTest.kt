@@ -0,0 +1,62 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.time
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+import io.kotest.matchers.shouldBe
+
+class DateAddTest : FunSpec({
+    val operatorName = "dateAdd"
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation(operatorName, DateAdd).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-01T00:00:00Z", 120)),
+                result = JsonLogicResult.Success("2024-01-01T00:02:00Z")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-31T12:00:00Z", 1, "days")),
+                result = JsonLogicResult.Success("2024-02-01T12:00:00Z")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-01T05:30:00+05:30", -1, "hours")),
+                result = JsonLogicResult.Success("2023-12-31T23:00:00Z")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-01", 1500, "millis")),
+                result = JsonLogicResult.Success("2024-01-01T00:00:01.500Z")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-01T00:00:00Z", 1.5, "hours")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("not a date", 1, "hours")),
+                result = JsonLogicResult.Failure.InvalidDate("not a date")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-01T00:00:00Z", Long.MAX_VALUE, "days")),
+                result = JsonLogicResult.Failure.InvalidDate("2024-01-01T00:00:00Z")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("+999999999-12-31T00:00:00Z", 1000, "days")),
+                result = JsonLogicResult.Failure.InvalidDate("+999999999-12-31T00:00:00Z")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-01T00:00:00Z", 1, "years")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult shouldBe testInput.result
+    }
+})
@@ -0,0 +1,66 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.time
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+import io.kotest.matchers.shouldBe
+
+class DateDiffTest : FunSpec({
+    val operatorName = "dateDiff"
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation(operatorName, DateDiff).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-02T00:00:00Z", "2024-01-01T00:00:00Z", "seconds")),
+                result = JsonLogicResult.Success(86400L)
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-02T00:00:00Z", "2024-01-01T00:00:00Z")),
+                result = JsonLogicResult.Success(86400L)
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-01T00:00:00Z", "2024-01-02T00:00:00Z", "hours")),
+                result = JsonLogicResult.Success(-24L)
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-01T05:30:00+05:30", "2024-01-01T00:00:00Z", "minutes")),
+                result = JsonLogicResult.Success(0L)
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-03-01", "2024-02-01", "days")),
+                result = JsonLogicResult.Success(29L)
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-01T00:01:59Z", "2024-01-01T00:00:00Z", "minutes")),
+                result = JsonLogicResult.Success(1L)
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-13-01T00:00:00Z", "2024-01-01T00:00:00Z")),
+                result = JsonLogicResult.Failure.InvalidDate("2024-13-01T00:00:00Z")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-02T00:00:00Z", "2024-01-01T00:00:00Z", "weeks")),
+                result = JsonLogicResult.Failure.NullResult
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-02T00:00:00Z", 5)),
+                result = JsonLogicResult.Failure.InvalidDate("5")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("+999999999-01-01T00:00:00Z", "-999999999-01-01T00:00:00Z", "millis")),
+                result = JsonLogicResult.Failure.InvalidDate("+999999999-01-01T00:00:00Z - -999999999-01-01T00:00:00Z")
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult shouldBe testInput.result
+    }
+})
@@ -0,0 +1,54 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.time
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.jsonLogic.stdlib.TestInput
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.datatest.withData
+import io.kotest.matchers.shouldBe
+
+class DateParseTest : FunSpec({
+    val operatorName = "dateParse"
+    val logicEngine = JsonLogicEngine.Builder().addStandardOperation(operatorName, DateParse).build()
+
+    withData(
+        nameFn = { input -> "Should evaluated ${input.expression} with given ${input.data} result in ${input.result}" },
+        ts = listOf(
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-01T00:00:00Z")),
+                result = JsonLogicResult.Success(1704067200000L)
+            ),
+            TestInput(
+                expression = mapOf(operatorName to "2024-01-01T05:30:00+05:30"),
+                result = JsonLogicResult.Success(1704067200000L)
+            ),
+            TestInput(
+                expression = mapOf(operatorName to "2024-01-01"),
+                result = JsonLogicResult.Success(1704067200000L)
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("2024-01-01T00:00:00")),
+                result = JsonLogicResult.Success(1704067200000L)
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("01/01/2024")),
+                result = JsonLogicResult.Failure.InvalidDate("01/01/2024")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf(1704067200000L)),
+                result = JsonLogicResult.Failure.InvalidDate("1704067200000")
+            ),
+            TestInput(
+                expression = mapOf(operatorName to listOf("+999999999-01-01T00:00:00Z")),
+                result = JsonLogicResult.Failure.InvalidDate("+999999999-01-01T00:00:00Z")
+            ),
+        )
+        // given
+    ) { testInput: TestInput ->
+        // when
+        val evaluationResult = logicEngine.evaluate(testInput.expression, testInput.data)
+
+        // then
+        evaluationResult shouldBe testInput.result
+    }
+})
@@ -0,0 +1,40 @@
+package io.github.ayushmaanbhav.jsonLogic.stdlib.time
+
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.kotest.core.spec.style.FunSpec
+import io.kotest.matchers.longs.shouldBeLessThan
+import io.kotest.matchers.should
+import io.kotest.matchers.types.beInstanceOf
+import java.time.Duration
+import java.time.Instant
+
+class NowTest : FunSpec({
+    val logicEngine = JsonLogicEngine.Builder()
+        .addStandardOperation("now", Now)
+        .addStandardOperation("dateDiff", DateDiff)
+        .build()
+
+    test("Should evaluate now into the current time as an ISO-8601 string") {
+        // when
+        val result = logicEngine.evaluate(mapOf("now" to emptyList<Any>()), null)
+
+        // then
+        result should beInstanceOf<JsonLogicResult.Success>()
+        val now = Instant.parse((result as JsonLogicResult.Success).value as String)
+        Duration.between(now, Instant.now()).abs().seconds shouldBeLessThan 5L
+    }
+
+    test("Should compute seconds since a timestamp using now") {
+        // given
+        val expression = mapOf("dateDiff" to listOf(mapOf("now" to emptyList<Any>()), mapOf("var" to "alertedAt")))
+        val data = mapOf("alertedAt" to Instant.now().minusSeconds(60).toString())
+
+        // when
+        val result = logicEngine.evaluate(expression, data)
+
+        // then
+        result should beInstanceOf<JsonLogicResult.Success>()
+        ((result as JsonLogicResult.Success).value as Long - 60L) shouldBeLessThan 5L
+    }
+})
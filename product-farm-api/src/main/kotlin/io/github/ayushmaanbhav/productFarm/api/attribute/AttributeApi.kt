@@ -20,6 +20,12 @@ interface AttributeApi {
         @RequestBody createRequest: CreateAttributeRequest,
     ): ResponseEntity<GenericResponse<Nothing>>
     
+    @PutMapping("/{productId}/attribute/bulk")
+    fun bulkCreate(
+        @PathVariable productId: String,
+        @RequestBody createRequests: List<CreateAttributeRequest>,
+    ): ResponseEntity<GenericResponse<Nothing>>
+    
     @GetMapping("/{productId}/attribute/{displayName}")
     fun get(
         @PathVariable productId: String,
@@ -0,0 +1,15 @@
+package io.github.ayushmaanbhav.productFarm.api.evaluation
+
+import io.github.ayushmaanbhav.common.model.response.GenericResponse
+import io.github.ayushmaanbhav.productFarm.api.evaluation.dto.EvaluateAdhocRequest
+import io.github.ayushmaanbhav.productFarm.api.evaluation.dto.EvaluateAdhocResponse
+import org.springframework.http.ResponseEntity
+import org.springframework.web.bind.annotation.PostMapping
+import org.springframework.web.bind.annotation.RequestBody
+import org.springframework.web.bind.annotation.RequestMapping
+
+@RequestMapping("/evaluation")
+interface EvaluationApi {
+    @PostMapping("/adhoc")
+    fun evaluateAdhoc(@RequestBody evaluateRequest: EvaluateAdhocRequest): ResponseEntity<GenericResponse<EvaluateAdhocResponse?>>
+}
@@ -0,0 +1,9 @@
+package io.github.ayushmaanbhav.productFarm.api.evaluation.dto
+
+data class AdhocRuleDto(
+    val id: String,
+    val type: String,
+    val inputAttributes: LinkedHashSet<String>,
+    val outputAttributes: LinkedHashSet<String>,
+    val expression: String,
+)
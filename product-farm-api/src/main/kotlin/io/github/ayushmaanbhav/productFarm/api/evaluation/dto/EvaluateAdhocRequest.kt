@@ -0,0 +1,10 @@
+package io.github.ayushmaanbhav.productFarm.api.evaluation.dto
+
+/**
+ * @param outputAttributes attributes to compute, all rule outputs when empty
+ */
+data class EvaluateAdhocRequest(
+    val rules: List<AdhocRuleDto>,
+    val input: LinkedHashMap<String, Any>,
+    val outputAttributes: List<String> = listOf(),
+)
@@ -0,0 +1,15 @@
+package io.github.ayushmaanbhav.productFarm.api.evaluation.dto
+
+/**
+ * @param executionPlan ids of the rules in the order they were executed
+ * @param provenance id of the rule which produced each output attribute
+ * @param skippedRules ids of the rules which were skipped since they timed out
+ * @param warnings conflicting outputs, e.g. an attribute written by more than one rule
+ */
+data class EvaluateAdhocResponse(
+    val outputs: Map<String, Any>,
+    val provenance: Map<String, String>,
+    val executionPlan: List<String>,
+    val skippedRules: List<String>,
+    val warnings: List<String>,
+)
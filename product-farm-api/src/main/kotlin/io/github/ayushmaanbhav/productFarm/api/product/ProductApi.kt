@@ -6,6 +6,7 @@ import io.github.ayushmaanbhav.productFarm.api.product.dto.CreateProductRequest
 import io.github.ayushmaanbhav.productFarm.api.product.dto.GetProductResponse
 import io.github.ayushmaanbhav.productFarm.api.product.dto.ProductApprovalRequest
 import io.github.ayushmaanbhav.productFarm.api.product.dto.ProductApprovalResponse
+import io.github.ayushmaanbhav.productFarm.api.product.dto.ProductDiffResponse
 import io.github.ayushmaanbhav.productFarm.api.product.dto.SubmitProductResponse
 import org.springframework.http.ResponseEntity
 import org.springframework.web.bind.annotation.GetMapping
@@ -23,6 +24,12 @@ interface ProductApi {
     @GetMapping("/{productId}")
     fun get(@PathVariable productId: String): ResponseEntity<GenericResponse<GetProductResponse?>>
     
+    @GetMapping("/{productId}/diff/{otherProductId}")
+    fun diff(
+        @PathVariable productId: String,
+        @PathVariable otherProductId: String
+    ): ResponseEntity<GenericResponse<ProductDiffResponse?>>
+    
     @PutMapping("/{parentProductId}/clone")
     fun clone(
         @PathVariable parentProductId: String,
@@ -1,5 +1,6 @@
 package io.github.ayushmaanbhav.productFarm.api.product.dto
 
+import io.github.ayushmaanbhav.productFarm.constant.ProductTemplateType
 import java.time.LocalDateTime
 
 data class CloneProductRequest(
@@ -8,4 +9,7 @@ data class CloneProductRequest(
     val effectiveFrom: LocalDateTime,
     val expiryAt: LocalDateTime,
     val description: String?,
+    val templateType: ProductTemplateType? = null,
+    // component types, datatypes and enumeration names to replace in the cloned product
+    val remap: Map<String, String>? = null,
 )
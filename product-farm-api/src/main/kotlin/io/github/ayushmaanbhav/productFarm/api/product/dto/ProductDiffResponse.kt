@@ -0,0 +1,24 @@
+package io.github.ayushmaanbhav.productFarm.api.product.dto
+
+/**
+ * entities are keyed by their path without the product id, functionalities by their name
+ */
+data class ProductDiffResponse(
+    val leftProductId: String,
+    val rightProductId: String,
+    val abstractAttributes: EntityDiff,
+    val attributes: EntityDiff,
+    val functionalities: EntityDiff,
+)
+
+data class EntityDiff(
+    val added: List<String>,
+    val removed: List<String>,
+    val modified: Map<String, List<FieldChange>>,
+)
+
+data class FieldChange(
+    val field: String,
+    val left: Any?,
+    val right: Any?,
+)
@@ -4,6 +4,7 @@ import io.github.ayushmaanbhav.common.model.response.GenericResponse
 import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.CreateProductFunctionalityRequest
 import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.GetProductFunctionalityResponse
 import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.ProductFunctionalityApprovalRequest
+import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.ProductFunctionalityPrecheckResponse
 import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.ProductFunctionalityStatusResponse
 import org.springframework.http.ResponseEntity
 import org.springframework.web.bind.annotation.GetMapping
@@ -33,6 +34,12 @@ interface ProductFunctionalityApi {
         @PathVariable name: String
     ): ResponseEntity<GenericResponse<ProductFunctionalityStatusResponse?>>
     
+    @GetMapping("/{productId}/functionality/{name}/precheck")
+    fun precheck(
+        @PathVariable productId: String,
+        @PathVariable name: String
+    ): ResponseEntity<GenericResponse<ProductFunctionalityPrecheckResponse?>>
+    
     @PostMapping("/{productId}/functionality/{name}/approve")
     fun approve(
         @PathVariable productId: String,
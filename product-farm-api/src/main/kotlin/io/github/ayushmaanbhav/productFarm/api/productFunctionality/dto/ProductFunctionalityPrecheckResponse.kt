@@ -0,0 +1,9 @@
+package io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto
+
+import io.github.ayushmaanbhav.productFarm.constant.ProductFunctionalityStatus
+
+data class ProductFunctionalityPrecheckResponse(
+    val status: ProductFunctionalityStatus,
+    val ready: Boolean,
+    val errors: List<String>,
+)
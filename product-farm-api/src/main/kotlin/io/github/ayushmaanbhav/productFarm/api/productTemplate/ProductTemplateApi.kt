@@ -2,6 +2,8 @@ package io.github.ayushmaanbhav.productFarm.api.productTemplate
 
 import io.github.ayushmaanbhav.common.model.response.GenericResponse
 import io.github.ayushmaanbhav.productFarm.api.productTemplate.dto.ProductTemplateEnumerationDto
+import io.github.ayushmaanbhav.productFarm.api.productTemplate.dto.ProductTemplateEnumerationListResponse
+import io.github.ayushmaanbhav.productFarm.constant.Constant
 import io.github.ayushmaanbhav.productFarm.constant.ProductTemplateType
 import org.springframework.http.ResponseEntity
 import org.springframework.web.bind.annotation.GetMapping
@@ -9,6 +11,7 @@ import org.springframework.web.bind.annotation.PathVariable
 import org.springframework.web.bind.annotation.PutMapping
 import org.springframework.web.bind.annotation.RequestBody
 import org.springframework.web.bind.annotation.RequestMapping
+import org.springframework.web.bind.annotation.RequestParam
 
 @RequestMapping("/productTemplate")
 interface ProductTemplateApi {
@@ -18,6 +21,13 @@ interface ProductTemplateApi {
         @RequestBody enumerationDto: ProductTemplateEnumerationDto
     ): ResponseEntity<GenericResponse<Nothing>>
     
+    @GetMapping("/{productTemplateType}/enum")
+    fun listEnumerations(
+        @PathVariable productTemplateType: ProductTemplateType,
+        @RequestParam(required = false) pageToken: String?,
+        @RequestParam(defaultValue = "${Constant.DEFAULT_PAGE_SIZE}") pageSize: Int,
+    ): ResponseEntity<GenericResponse<ProductTemplateEnumerationListResponse?>>
+    
     @GetMapping("/{productTemplateType}/enum/{name}")
     fun getEnumeration(
         @PathVariable productTemplateType: ProductTemplateType,
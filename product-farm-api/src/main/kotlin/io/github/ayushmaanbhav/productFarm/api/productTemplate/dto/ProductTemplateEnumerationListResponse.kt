@@ -0,0 +1,6 @@
+package io.github.ayushmaanbhav.productFarm.api.productTemplate.dto
+
+data class ProductTemplateEnumerationListResponse(
+    val enumerations: List<ProductTemplateEnumerationDto>,
+    val nextPageToken: String?,
+)
@@ -0,0 +1,45 @@
+package io.github.ayushmaanbhav.productFarm.config
+
+import io.github.ayushmaanbhav.common.model.RequestMetadata
+import org.springframework.beans.factory.annotation.Value
+import org.springframework.context.annotation.Configuration
+import org.springframework.web.servlet.config.annotation.CorsRegistry
+import org.springframework.web.servlet.config.annotation.WebMvcConfigurer
+
+@Configuration
+class CorsConfig(
+    @Value("\${cors.allowed-origins:}") private val allowedOrigins: List<String>,
+    @Value("\${cors.allowed-methods:GET,POST,PUT,DELETE,OPTIONS}") private val allowedMethods: List<String>,
+    @Value("\${cors.allowed-headers:*}") private val allowedHeaders: List<String>,
+    @Value("\${cors.allow-credentials:false}") private val allowCredentials: Boolean,
+    @Value("\${cors.max-age:1800}") private val maxAge: Long,
+    @Value("\${cors.dev-mode:false}") private val devMode: Boolean,
+) : WebMvcConfigurer {
+    init {
+        // a wildcard pattern with credentials lets sites matching it make authenticated requests, eg. "https://*"
+        // lets any site do so, so wildcard patterns with credentials are only allowed in dev mode
+        require(devMode || !allowCredentials || allowedOrigins.none { it.contains(ANY_ORIGIN) }) {
+            "cors.allowed-origins can not contain $ANY_ORIGIN patterns when cors.allow-credentials is set, unless cors.dev-mode is set"
+        }
+    }
+    
+    override fun addCorsMappings(registry: CorsRegistry) {
+        // cross-origin requests are rejected unless origins are configured, dev mode allows any origin by default
+        val origins = when {
+            allowedOrigins.isNotEmpty() -> allowedOrigins
+            devMode -> listOf(ANY_ORIGIN)
+            else -> return
+        }
+        registry.addMapping("/**")
+            .allowedOriginPatterns(*origins.toTypedArray())
+            .allowedMethods(*allowedMethods.toTypedArray())
+            .allowedHeaders(*allowedHeaders.toTypedArray())
+            .exposedHeaders(RequestMetadata.CORRELATION_ID_HEADER)
+            .allowCredentials(allowCredentials)
+            .maxAge(maxAge)
+    }
+    
+    companion object {
+        const val ANY_ORIGIN = "*"
+    }
+}
@@ -1,15 +1,31 @@
 package io.github.ayushmaanbhav.productFarm.config
 
+import io.github.ayushmaanbhav.common.interceptor.RequestBodyLimitFilter
 import io.github.ayushmaanbhav.common.interceptor.RequestMetadataHandler
+import org.springframework.beans.factory.annotation.Value
+import org.springframework.boot.web.servlet.FilterRegistrationBean
+import org.springframework.context.annotation.Bean
 import org.springframework.context.annotation.Configuration
 import org.springframework.web.servlet.config.annotation.InterceptorRegistry
 import org.springframework.web.servlet.config.annotation.WebMvcConfigurer
 
 @Configuration
-class RequestHandlerConfig : WebMvcConfigurer {
+class RequestHandlerConfig(
+    @Value("\${request.body.max-bytes}") private val maxBodyBytes: Long,
+    @Value("\${request.body.bulk.max-bytes}") private val maxBulkBodyBytes: Long,
+) : WebMvcConfigurer {
     private val requestMetadataHandler = RequestMetadataHandler()
     
     override fun addInterceptors(registry: InterceptorRegistry) {
         registry.addInterceptor(requestMetadataHandler)
     }
+    
+    // a filter, since the body has to be limited before anything reads it
+    @Bean
+    fun requestBodyLimitFilter(): FilterRegistrationBean<RequestBodyLimitFilter> =
+        FilterRegistrationBean(RequestBodyLimitFilter(maxBodyBytes, linkedMapOf(BULK_PATH_PATTERN to maxBulkBodyBytes)))
+    
+    companion object {
+        const val BULK_PATH_PATTERN = "/**/bulk"
+    }
 }
@@ -4,14 +4,18 @@ import com.fasterxml.jackson.databind.ObjectMapper
 import io.github.ayushmaanbhav.jsonLogic.config.MathContext
 import io.github.ayushmaanbhav.ruleEngine.config.Config
 import io.github.ayushmaanbhav.ruleEngine.model.CachePolicy
+import org.springframework.beans.factory.annotation.Value
 import org.springframework.context.annotation.Bean
 import org.springframework.context.annotation.Configuration
 
 @Configuration
 class RuleFrameworkConfig {
     @Bean
-    fun ruleFrameworkConfig(): Config {
-        return RuleFrameworkConfig()
+    fun ruleFrameworkConfig(
+        @Value("\${rule.timeout-millis:$DEFAULT_RULE_TIMEOUT_MILLIS}") ruleTimeoutMillis: Long,
+        @Value("\${rule.executor-threads:${Config.DEFAULT_RULE_EXECUTOR_THREADS}}") ruleExecutorThreads: Int,
+    ): Config {
+        return RuleFrameworkConfig(ruleTimeoutMillis = ruleTimeoutMillis, ruleExecutorThreads = ruleExecutorThreads)
     }
 
     data class RuleFrameworkConfig(
@@ -19,6 +23,13 @@ class RuleFrameworkConfig {
         override val objectMapper: ObjectMapper = Config.objectMapperBuilder().build(),
         override val cachePolicy: CachePolicy = Config.DEFAULT_USE_CACHE_POLICY,
         override val maxRuleDgCacheSize: Long = Config.DEFAULT_MAX_CACHE_SIZE,
-        override val maxQueryCacheSize: Long = Config.DEFAULT_MAX_CACHE_SIZE
+        override val maxQueryCacheSize: Long = Config.DEFAULT_MAX_CACHE_SIZE,
+        override val ruleTimeoutMillis: Long = DEFAULT_RULE_TIMEOUT_MILLIS,
+        override val ruleExecutorThreads: Int = Config.DEFAULT_RULE_EXECUTOR_THREADS
     ) : Config
+
+    companion object {
+        // ad hoc rules are supplied by the caller, so rules run with a timeout unless configured otherwise
+        const val DEFAULT_RULE_TIMEOUT_MILLIS = 1000L
+    }
 }
@@ -6,12 +6,17 @@ class Constant {
         const val CREATED_MESSAGE = "Created"
         const val NOT_FOUND_MESSAGE = "Not Found"
         
+        // Api paging
+        const val DEFAULT_PAGE_SIZE = 50
+        const val MAX_PAGE_SIZE = 200
+        
         // DB model field constraints
         const val COMPONENT_SEPARATOR = ":"
         const val HUMAN_FORMAT_COMPONENT_SEPARATOR = "."
         const val ORIGINAL_FORMAT_COMPONENT_SEPARATOR = "."
         const val ATTRIBUTE_NAME_SEPARATOR = "."
         const val ABSTRACT_PATH_NAME = "abstract-path"
+        const val TAG_NAMESPACE_SEPARATOR = ":"
         val PRODUCT_ID_REGEX = "[a-zA-Z]([_][a-zA-Z0-9]|[a-zA-Z0-9]){0,50}".toRegex()
         val COMPONENT_ID_REGEX = "[a-z]([-][a-z0-9]|[a-z0-9]){0,50}".toRegex()
         val COMPONENT_TYPE_REGEX = "[a-z]([-][a-z]|[a-z]){0,50}".toRegex()
@@ -29,7 +34,7 @@ class Constant {
         val ORIGINAL_ATTRIBUTE_NAME_REGEX = "[a-zA-Z]([$ATTRIBUTE_NAME_SEPARATOR][a-zA-Z]|[a-zA-Z0-9]){0,100}".toRegex()
         val ORIGINAL_COMPONENT_TYPE_REGEX = "[a-zA-Z]([_][a-zA-Z]|[a-zA-Z]){0,50}".toRegex()
         val ORIGINAL_COMPONENT_ID_REGEX = "[a-zA-Z]([-_()][a-zA-Z0-9]|[a-zA-Z0-9]){0,50}".toRegex()
-        val TAG_REGEX = "[a-z]([-][a-z]|[a-z]){0,50}".toRegex()
+        val TAG_REGEX = "[a-z]([-][a-z]|[a-z]){0,50}([$TAG_NAMESPACE_SEPARATOR][a-z]([-][a-z]|[a-z]){0,50})*".toRegex()
         val DATATYPE_REGEX = "[a-z]([-][a-z]|[a-z]){0,50}".toRegex()
         val FUNCTIONALITY_NAME_REGEX = "[a-z]([-][a-z]|[a-z]){0,50}".toRegex()
         val ENUMERATION_NAME_REGEX = "[a-z]([-][a-z]|[a-z]){0,50}".toRegex()
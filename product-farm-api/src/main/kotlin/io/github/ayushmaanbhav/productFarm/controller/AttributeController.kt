@@ -26,6 +26,13 @@ class AttributeController(
         return GenericResponse.getResponseMessageWithCode(Constant.CREATED_MESSAGE, HttpStatus.CREATED)
     }
     
+    override fun bulkCreate(
+        productId: String, createRequests: List<CreateAttributeRequest>
+    ): ResponseEntity<GenericResponse<Nothing>> {
+        attributeService.bulkCreate(productId, createRequests)
+        return GenericResponse.getResponseMessageWithCode(Constant.CREATED_MESSAGE, HttpStatus.CREATED)
+    }
+    
     override fun get(productId: String, displayName: String): ResponseEntity<GenericResponse<GetAttributeResponse?>> =
         attributeService.get(productId, displayName).map {
             GenericResponse.getResponseWithCode(it, HttpStatus.OK)
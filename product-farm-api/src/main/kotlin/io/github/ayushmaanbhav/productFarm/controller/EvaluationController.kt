@@ -0,0 +1,20 @@
+package io.github.ayushmaanbhav.productFarm.controller
+
+import com.github.lkqm.spring.api.version.ApiVersion
+import io.github.ayushmaanbhav.common.model.response.GenericResponse
+import io.github.ayushmaanbhav.productFarm.api.evaluation.EvaluationApi
+import io.github.ayushmaanbhav.productFarm.api.evaluation.dto.EvaluateAdhocRequest
+import io.github.ayushmaanbhav.productFarm.api.evaluation.dto.EvaluateAdhocResponse
+import io.github.ayushmaanbhav.productFarm.service.EvaluationService
+import org.springframework.http.HttpStatus
+import org.springframework.http.ResponseEntity
+import org.springframework.web.bind.annotation.RestController
+
+@ApiVersion("0")
+@RestController
+class EvaluationController(
+    private val evaluationService: EvaluationService,
+) : EvaluationApi {
+    override fun evaluateAdhoc(evaluateRequest: EvaluateAdhocRequest): ResponseEntity<GenericResponse<EvaluateAdhocResponse?>> =
+        GenericResponse.getResponseWithCode(evaluationService.evaluateAdhoc(evaluateRequest), HttpStatus.OK)
+}
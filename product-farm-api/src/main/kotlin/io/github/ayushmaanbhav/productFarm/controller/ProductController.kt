@@ -8,9 +8,11 @@ import io.github.ayushmaanbhav.productFarm.api.product.dto.CreateProductRequest
 import io.github.ayushmaanbhav.productFarm.api.product.dto.GetProductResponse
 import io.github.ayushmaanbhav.productFarm.api.product.dto.ProductApprovalRequest
 import io.github.ayushmaanbhav.productFarm.api.product.dto.ProductApprovalResponse
+import io.github.ayushmaanbhav.productFarm.api.product.dto.ProductDiffResponse
 import io.github.ayushmaanbhav.productFarm.api.product.dto.SubmitProductResponse
 import io.github.ayushmaanbhav.productFarm.constant.Constant
 import io.github.ayushmaanbhav.productFarm.service.CloneProductService
+import io.github.ayushmaanbhav.productFarm.service.ProductDiffService
 import io.github.ayushmaanbhav.productFarm.service.ProductService
 import org.springframework.http.HttpStatus
 import org.springframework.http.ResponseEntity
@@ -21,6 +23,7 @@ import org.springframework.web.bind.annotation.RestController
 class ProductController(
     private val productService: ProductService,
     private val cloneProductService: CloneProductService,
+    private val productDiffService: ProductDiffService,
 ) : ProductApi {
     
     override fun create(createRequest: CreateProductRequest): ResponseEntity<GenericResponse<Nothing>> {
@@ -35,6 +38,13 @@ class ProductController(
             GenericResponse.getResponseMessageWithCode(Constant.NOT_FOUND_MESSAGE, HttpStatus.NOT_FOUND)
         }
     
+    override fun diff(productId: String, otherProductId: String): ResponseEntity<GenericResponse<ProductDiffResponse?>> =
+        productDiffService.diff(productId, otherProductId).map {
+            GenericResponse.getResponseWithCode(it, HttpStatus.OK)
+        }.orElseGet {
+            GenericResponse.getResponseMessageWithCode(Constant.NOT_FOUND_MESSAGE, HttpStatus.NOT_FOUND)
+        }
+    
     override fun clone(
         parentProductId: String, cloneRequest: CloneProductRequest
     ): ResponseEntity<GenericResponse<Nothing>> {
@@ -6,6 +6,7 @@ import io.github.ayushmaanbhav.productFarm.api.productFunctionality.ProductFunct
 import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.CreateProductFunctionalityRequest
 import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.GetProductFunctionalityResponse
 import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.ProductFunctionalityApprovalRequest
+import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.ProductFunctionalityPrecheckResponse
 import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.ProductFunctionalityStatusResponse
 import io.github.ayushmaanbhav.productFarm.constant.Constant
 import io.github.ayushmaanbhav.productFarm.service.ProductFunctionalityService
@@ -38,6 +39,13 @@ class ProductFunctionalityController(
             GenericResponse.getResponseMessageWithCode(Constant.NOT_FOUND_MESSAGE, HttpStatus.NOT_FOUND)
         }
 
+    override fun precheck(productId: String, name: String): ResponseEntity<GenericResponse<ProductFunctionalityPrecheckResponse?>> =
+        productFunctionalityService.precheck(productId, name).map {
+            GenericResponse.getResponseWithCode(it, HttpStatus.OK)
+        }.orElseGet {
+            GenericResponse.getResponseMessageWithCode(Constant.NOT_FOUND_MESSAGE, HttpStatus.NOT_FOUND)
+        }
+
     override fun approve(
         productId: String, name: String, approvalRequest: ProductFunctionalityApprovalRequest
     ): ResponseEntity<GenericResponse<ProductFunctionalityStatusResponse?>> =
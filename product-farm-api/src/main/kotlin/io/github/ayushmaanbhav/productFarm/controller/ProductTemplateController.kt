@@ -4,6 +4,7 @@ import com.github.lkqm.spring.api.version.ApiVersion
 import io.github.ayushmaanbhav.common.model.response.GenericResponse
 import io.github.ayushmaanbhav.productFarm.api.productTemplate.ProductTemplateApi
 import io.github.ayushmaanbhav.productFarm.api.productTemplate.dto.ProductTemplateEnumerationDto
+import io.github.ayushmaanbhav.productFarm.api.productTemplate.dto.ProductTemplateEnumerationListResponse
 import io.github.ayushmaanbhav.productFarm.constant.Constant
 import io.github.ayushmaanbhav.productFarm.constant.ProductTemplateType
 import io.github.ayushmaanbhav.productFarm.service.ProductTemplateService
@@ -23,6 +24,13 @@ class ProductTemplateController(
         return GenericResponse.getResponseMessageWithCode(Constant.CREATED_MESSAGE, HttpStatus.CREATED)
     }
     
+    override fun listEnumerations(
+        productTemplateType: ProductTemplateType, pageToken: String?, pageSize: Int
+    ): ResponseEntity<GenericResponse<ProductTemplateEnumerationListResponse?>> =
+        GenericResponse.getResponseWithCode(
+            productTemplateService.listEnumerations(productTemplateType, pageToken, pageSize), HttpStatus.OK
+        )
+    
     override fun getEnumeration(
         productTemplateType: ProductTemplateType, name: String
     ): ResponseEntity<GenericResponse<ProductTemplateEnumerationDto?>> =
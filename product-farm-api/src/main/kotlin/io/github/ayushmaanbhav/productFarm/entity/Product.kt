@@ -25,6 +25,7 @@ data class Product(
     val templateType: ProductTemplateType,
     val parentProductId: String?,
     val description: String?,
+    val tenantId: String?,
 ) : AbstractEntity<Product>(Product::class) {
     override fun equals(other: Any?) = super.equals(other)
     override fun hashCode() = super.hashCode()
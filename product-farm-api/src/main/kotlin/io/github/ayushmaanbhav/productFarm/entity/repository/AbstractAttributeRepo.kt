@@ -5,4 +5,6 @@ import org.springframework.data.jpa.repository.JpaRepository
 import org.springframework.stereotype.Repository
 
 @Repository
-interface AbstractAttributeRepo : JpaRepository<AbstractAttribute, String>
+interface AbstractAttributeRepo : JpaRepository<AbstractAttribute, String> {
+    fun findAllByProductId(productId: String): List<AbstractAttribute>
+}
@@ -8,4 +8,6 @@ import org.springframework.stereotype.Repository
 @Repository
 interface AbstractAttributeTagRepo : JpaRepository<AbstractAttributeTag, AbstractAttributeTagId>  {
     fun getByProductIdAndTag(productId: String, tag: String): List<AbstractAttributeTag>
+    
+    fun getByProductIdAndTagStartingWith(productId: String, tagPrefix: String): List<AbstractAttributeTag>
 }
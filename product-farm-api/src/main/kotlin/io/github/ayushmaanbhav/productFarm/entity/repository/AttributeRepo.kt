@@ -9,4 +9,6 @@ interface AttributeRepo : JpaRepository<Attribute, String> {
     fun findAllByProductIdOrderByPathAsc(productId: String): List<Attribute>
     
     fun findAllByAbstractAttribute_AbstractPath(abstractPath: String): List<Attribute>
+    
+    fun findAllByAbstractAttribute_AbstractPathIn(abstractPaths: Collection<String>): List<Attribute>
 }
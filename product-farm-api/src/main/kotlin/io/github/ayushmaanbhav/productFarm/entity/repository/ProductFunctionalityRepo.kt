@@ -9,4 +9,5 @@ import org.springframework.stereotype.Repository
 interface ProductFunctionalityRepo : JpaRepository<ProductFunctionality, String> {
     fun existsByProductIdAndName(productId: String, name: String): Boolean
     fun findByProductIdAndName(productId: String, name: String): Optional<ProductFunctionality>
+    fun findAllByProductId(productId: String): List<ProductFunctionality>
 }
@@ -3,12 +3,24 @@ package io.github.ayushmaanbhav.productFarm.entity.repository
 import io.github.ayushmaanbhav.productFarm.constant.ProductTemplateType
 import io.github.ayushmaanbhav.productFarm.entity.ProductTemplateEnumeration
 import java.util.*
+import org.springframework.data.domain.Pageable
 import org.springframework.data.jpa.repository.JpaRepository
 import org.springframework.stereotype.Repository
 
 @Repository
 interface ProductTemplateEnumerationRepo : JpaRepository<ProductTemplateEnumeration, String> {
     
+    fun findAllByProductTemplateTypeOrderByNameAsc(
+        productTemplateType: ProductTemplateType,
+        pageable: Pageable
+    ): List<ProductTemplateEnumeration>
+    
+    fun findAllByProductTemplateTypeAndNameGreaterThanOrderByNameAsc(
+        productTemplateType: ProductTemplateType,
+        name: String,
+        pageable: Pageable
+    ): List<ProductTemplateEnumeration>
+    
     fun existsByProductTemplateTypeAndName(productTemplateType: ProductTemplateType, name: String): Boolean
     
     fun findByProductTemplateTypeAndName(
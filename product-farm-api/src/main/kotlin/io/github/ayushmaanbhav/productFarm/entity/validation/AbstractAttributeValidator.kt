@@ -6,6 +6,7 @@ import io.github.ayushmaanbhav.common.model.response.ErrorDetail
 import io.github.ayushmaanbhav.productFarm.constant.Constant
 import io.github.ayushmaanbhav.productFarm.constant.ProductStatus.DRAFT
 import io.github.ayushmaanbhav.productFarm.entity.AbstractAttribute
+import io.github.ayushmaanbhav.productFarm.entity.relationship.AbstractAttributeTag
 import io.github.ayushmaanbhav.productFarm.entity.repository.AbstractAttributeRepo
 import io.github.ayushmaanbhav.productFarm.entity.repository.ProductRepo
 import io.github.ayushmaanbhav.productFarm.exception.ProductFarmServiceException
@@ -87,12 +88,7 @@ class AbstractAttributeValidator(
                         }
                 AbstractAttribute::tags ->
                     createError()
-                        .takeUnless { abstractAttribute.componentType.let(Constant.TAG_REGEX::matches) }
-                        .takeUnless {
-                            abstractAttribute.tags
-                                .mapIndexed { index, tag -> tag.order == index }
-                                .all { it }
-                        }
+                        .takeUnless { isValidTags(abstractAttribute.tags) }
                 else -> throw ProductFarmServiceException(
                     "Missing validation for property", arrayOf(property.name, property.javaClass.name)
                 )
@@ -106,6 +102,10 @@ class AbstractAttributeValidator(
         return true
     }
     
+    // tags may be namespaced, eg. pricing:discount, and have to be in order
+    fun isValidTags(tags: List<AbstractAttributeTag>): Boolean =
+        tags.withIndex().all { (index, tag) -> tag.order == index && tag.id.tag.let(Constant.TAG_REGEX::matches) }
+    
     companion object {
         private val log = LogManager.getLogger()
     }
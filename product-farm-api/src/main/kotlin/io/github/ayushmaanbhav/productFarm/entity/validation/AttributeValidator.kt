@@ -65,8 +65,15 @@ class AttributeValidator(
         return true
     }
 
-    private fun isValidValue(attribute: Attribute): ErrorDetail? = createError()
-        .takeUnless { attribute.value?.let { attributeValue -> isValidAttributeValue(attribute, attributeValue) } ?: true }
+    private fun isValidValue(attribute: Attribute): ErrorDetail? = attribute.value
+        ?.takeUnless { attributeValue -> isValidAttributeValue(attribute, attributeValue) }
+        ?.let { attributeValue -> constraintRuleError(attribute, attributeValue) ?: createError() }
+
+    // reports the description of the violated constraint rule, so the caller knows why the value was rejected
+    private fun constraintRuleError(attribute: Attribute, attributeValue: JsonNode): ErrorDetail? =
+        attribute.abstractAttribute.constraintRule
+            ?.takeUnless { isSatisfiesConstraintRuleIfPresent(attribute, attributeValue) }
+            ?.let { createError("Value does not satisfy the constraint rule: ${it.description ?: it.id}") }
 
     private fun isValidType(attribute: Attribute): ErrorDetail? = createError()
         .takeUnless {
@@ -157,9 +164,16 @@ class AttributeValidator(
 
     private fun isSatisfiesConstraintRuleIfPresent(attribute: Attribute, attributeValue: JsonNode): Boolean =
         attribute.abstractAttribute.constraintRule?.let { constraintRule ->
-            ruleUtil.executeConstraint(ruleTransformer.forward(constraintRule), attributeValue)
+            ruleUtil.executeConstraint(ruleTransformer.forward(constraintRule), attributeValue, constraintContext(attribute))
         } ?: true
 
+    private fun constraintContext(attribute: Attribute): Map<String, Any?> = mapOf(
+        "productId" to attribute.productId,
+        "componentType" to attribute.abstractAttribute.componentType,
+        "componentId" to attribute.abstractAttribute.componentId,
+        "path" to attribute.path,
+    )
+
     private fun isValidDatatype(attribute: Attribute, attributeValue: JsonNode): Boolean =
         when (attribute.abstractAttribute.datatype.type) {
             OBJECT -> attributeValue.isObject
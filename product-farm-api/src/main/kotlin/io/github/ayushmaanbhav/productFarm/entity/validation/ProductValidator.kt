@@ -51,6 +51,7 @@ class ProductValidator(
                             || (product.status == DISCONTINUED && LocalDateTime.now().isAfter(product.expiryAt))
                         }
                 Product::templateType -> null // enum
+                Product::tenantId -> null // request scoped
                 else -> throw ProductFarmServiceException(
                     "Missing validation for property", arrayOf(property.name, property.javaClass.name)
                 )
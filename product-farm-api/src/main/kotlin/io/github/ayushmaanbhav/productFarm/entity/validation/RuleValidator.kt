@@ -30,7 +30,8 @@ class RuleValidator(
                 Rule::compiledExpression ->
                     createError()
                         .takeUnless {
-                            rule.compiledExpression == ruleUtil.compileExpression(ruleTransformer.forward(rule))
+                            // rules compiled before canonicalization are stored in their original form
+                            ruleUtil.canonicalize(rule.compiledExpression) == ruleUtil.compileExpression(ruleTransformer.forward(rule))
                         }
                 Rule::id ->
                     createError()
@@ -0,0 +1,22 @@
+package io.github.ayushmaanbhav.productFarm.model
+
+import io.github.ayushmaanbhav.ruleEngine.model.rule.Rule
+
+/**
+ * rule which is only held in memory, for a single evaluation or while validating a batch of rules,
+ * identified by the given id
+ */
+data class AdhocRule(
+    val id: String,
+    val type: String,
+    val inputAttributes: LinkedHashSet<String>,
+    val outputAttributes: LinkedHashSet<String>,
+    val expression: String,
+) : Rule {
+    override fun getId() = id
+    override fun ruleType() = type
+    override fun getInputAttributePaths() = inputAttributes
+    override fun getOutputAttributePaths() = outputAttributes
+    override fun getTags() = HashSet<String>()
+    override fun getExpression() = expression
+}
@@ -3,8 +3,11 @@ package io.github.ayushmaanbhav.productFarm.service
 import io.github.ayushmaanbhav.common.exception.ValidatorException
 import io.github.ayushmaanbhav.common.model.response.ErrorDetail
 import io.github.ayushmaanbhav.productFarm.api.attribute.dto.CreateAbstractAttributeRequest
+import io.github.ayushmaanbhav.productFarm.api.attribute.dto.CreateRuleRequest
 import io.github.ayushmaanbhav.productFarm.api.attribute.dto.GetAbstractAttributeResponse
 import io.github.ayushmaanbhav.productFarm.constant.Constant
+import io.github.ayushmaanbhav.productFarm.constant.DisplayNameFormat
+import io.github.ayushmaanbhav.productFarm.entity.AbstractAttribute
 import io.github.ayushmaanbhav.productFarm.entity.compositeId.AttributeDisplayNameId
 import io.github.ayushmaanbhav.productFarm.entity.repository.AbstractAttributeRepo
 import io.github.ayushmaanbhav.productFarm.entity.repository.AttributeDisplayNameRepo
@@ -13,6 +16,7 @@ import io.github.ayushmaanbhav.productFarm.entity.repository.ProductRepo
 import io.github.ayushmaanbhav.productFarm.entity.repository.ProductTemplateEnumerationRepo
 import io.github.ayushmaanbhav.productFarm.transformer.CreateAbstractAttributeTransformer
 import io.github.ayushmaanbhav.productFarm.transformer.GetAbstractAttributeTransformer
+import io.github.ayushmaanbhav.productFarm.transformer.RuleTransformer
 import io.github.ayushmaanbhav.productFarm.util.createError
 import io.github.ayushmaanbhav.productFarm.util.generatePath
 import jakarta.transaction.Transactional
@@ -29,23 +33,108 @@ class AbstractAttributeService(
     private val productRepo: ProductRepo,
     private val enumerationRepo: ProductTemplateEnumerationRepo,
     private val attributeDisplayNameRepo: AttributeDisplayNameRepo,
+    private val ruleTransformer: RuleTransformer,
+    private val tenantAccessService: TenantAccessService,
 ) {
     @Transactional
     fun create(productId: String, request: CreateAbstractAttributeRequest) {
+        tenantAccessService.checkAccess(productId)
         validateCreateRequest(productId, request)
         abstractAttributeRepo.save(createAbstractAttributeTransformer.forward(Pair(productId, request)))
     }
     
     fun get(productId: String, displayName: String): Optional<GetAbstractAttributeResponse> {
+        tenantAccessService.checkAccess(productId)
         return attributeDisplayNameRepo.findById(AttributeDisplayNameId(productId, displayName))
             .flatMap { it.abstractPath?.let { it1 -> abstractAttributeRepo.findById(it1) } }
             .map { getAbstractAttributeTransformer.forward(it) }
     }
     
-    fun clone(parentProductId: String, productId: String) {
-        TODO()
+    /**
+     * copies all abstract attributes of the parent product, replacing component types, datatypes
+     * and enumeration names present in remap, paths and references are rebuilt for the new product,
+     * returns the new abstract path of every abstract path of the parent product
+     */
+    @Transactional
+    fun clone(parentProductId: String, productId: String, remap: Map<String, String>): Map<String, String> {
+        val parentAttributes = abstractAttributeRepo.findAllByProductId(parentProductId)
+        val originalIds = parentAttributes.associate { it.abstractPath to getOriginalId(it, remap) }
+        val pathRemap = originalIds.mapValues { (_, id) -> generatePath(productId, id.componentType, id.componentId, id.name) }
+        val errorList = mutableListOf<ErrorDetail>()
+        originalIds.values.map { it.componentType }.distinct()
+            .filterNot(Constant.ORIGINAL_COMPONENT_TYPE_REGEX::matches)
+            .forEach { errorList.add(createError("Please enter a valid componentType: $it")) }
+        val remapPath = { path: String ->
+            pathRemap[path] ?: path.also {
+                if (it.startsWith(parentProductId + Constant.COMPONENT_SEPARATOR)) {
+                    errorList.add(createError("Unmapped reference to parent product attribute: $it"))
+                }
+            }
+        }
+        val product = productRepo.getReferenceById(productId)
+        val requests = parentAttributes.map { attribute ->
+            val datatype = remap[attribute.datatype.name] ?: attribute.datatype.name
+            if (datatypeRepo.existsById(datatype).not()) {
+                errorList.add(createError("Datatype does not exist for this id: $datatype"))
+            }
+            val enumeration = attribute.enumeration?.let { remap[it.name] ?: it.name }
+            if (enumeration != null
+                && enumerationRepo.existsByProductTemplateTypeAndName(product.templateType, enumeration).not()
+            ) {
+                errorList.add(createError("Enumeration does not exist for this id: $enumeration"))
+            }
+            val originalId = originalIds.getValue(attribute.abstractPath)
+            CreateAbstractAttributeRequest(
+                name = originalId.name,
+                componentType = originalId.componentType,
+                componentId = originalId.componentId,
+                tags = attribute.tags.sortedBy { it.order }.map { it.id.tag }.toCollection(LinkedHashSet()),
+                datatype = datatype,
+                enumeration = enumeration,
+                relatedAttributes = attribute.relatedAttributes.sortedBy { it.order }
+                    .map { remapPath(it.id.referenceAbstractPath) }.toCollection(LinkedHashSet()),
+                constraintExpression = attribute.constraintRule?.let { ruleTransformer.forward(it) }?.let {
+                    CreateRuleRequest(
+                        type = it.type,
+                        inputAttributes = it.inputAttributes.map(remapPath).toCollection(LinkedHashSet()),
+                        outputAttributes = it.outputAttributes.map(remapPath).toCollection(LinkedHashSet()),
+                        displayExpression = it.displayExpression,
+                        description = it.description,
+                    )
+                },
+                immutable = attribute.immutable,
+                description = attribute.description,
+            )
+        }
+        if (errorList.isNotEmpty()) {
+            throw ValidatorException(HttpStatus.BAD_REQUEST.value(), errorList)
+        }
+        abstractAttributeRepo.saveAll(requests.map { createAbstractAttributeTransformer.forward(Pair(productId, it)) })
+        return pathRemap
     }
     
+    /**
+     * reads the component type, component id and name in the format the attribute was created with,
+     * the paths only keep the normalized form, the component type is replaced if present in remap
+     */
+    private fun getOriginalId(attribute: AbstractAttribute, remap: Map<String, String>): OriginalAbstractAttributeId {
+        val parts = attribute.displayNames
+            .firstOrNull { it.path == null && it.displayNameFormat == DisplayNameFormat.ORIGINAL }
+            ?.id?.displayName?.split(Constant.ORIGINAL_FORMAT_COMPONENT_SEPARATOR)
+        val nameIndex = if (attribute.componentId == null) 1 else 2
+        val componentType = parts?.first() ?: attribute.componentType
+        return OriginalAbstractAttributeId(
+            componentType = remap[componentType] ?: remap[attribute.componentType] ?: componentType,
+            componentId = if (attribute.componentId == null) null else parts?.get(1) ?: attribute.componentId,
+            name = parts?.drop(nameIndex)?.joinToString(Constant.ORIGINAL_FORMAT_COMPONENT_SEPARATOR)
+                ?: attribute.abstractPath.removePrefix(
+                    generatePath(attribute.productId, attribute.componentType, attribute.componentId, "")
+                ),
+        )
+    }
+    
+    private data class OriginalAbstractAttributeId(val componentType: String, val componentId: String?, val name: String)
+    
     private fun validateCreateRequest(productId: String, request: CreateAbstractAttributeRequest) {
         val errorList = mutableListOf<ErrorDetail>()
         val abstractPath = generatePath(productId, request.componentType, request.componentId, request.name)
@@ -3,11 +3,14 @@ package io.github.ayushmaanbhav.productFarm.service
 import io.github.ayushmaanbhav.common.exception.ValidatorException
 import io.github.ayushmaanbhav.common.model.response.ErrorDetail
 import io.github.ayushmaanbhav.productFarm.api.attribute.dto.CreateAttributeRequest
+import io.github.ayushmaanbhav.productFarm.api.attribute.dto.CreateRuleRequest
 import io.github.ayushmaanbhav.productFarm.api.attribute.dto.GetAttributeListByTagResponse
 import io.github.ayushmaanbhav.productFarm.api.attribute.dto.GetAttributeResponse
 import io.github.ayushmaanbhav.productFarm.api.attribute.dto.GetFunctionalityAttributeListResponse
 import io.github.ayushmaanbhav.productFarm.constant.AttributeValueType
 import io.github.ayushmaanbhav.productFarm.constant.Constant
+import io.github.ayushmaanbhav.productFarm.constant.DisplayNameFormat
+import io.github.ayushmaanbhav.productFarm.entity.Attribute
 import io.github.ayushmaanbhav.productFarm.entity.compositeId.AttributeDisplayNameId
 import io.github.ayushmaanbhav.productFarm.entity.repository.AbstractAttributeRepo
 import io.github.ayushmaanbhav.productFarm.entity.repository.AbstractAttributeTagRepo
@@ -15,13 +18,18 @@ import io.github.ayushmaanbhav.productFarm.entity.repository.AttributeDisplayNam
 import io.github.ayushmaanbhav.productFarm.entity.repository.AttributeRepo
 import io.github.ayushmaanbhav.productFarm.entity.repository.ProductFunctionalityRepo
 import io.github.ayushmaanbhav.productFarm.entity.repository.ProductRepo
+import io.github.ayushmaanbhav.productFarm.model.AdhocRule
 import io.github.ayushmaanbhav.productFarm.transformer.CreateAttributeTransformer
 import io.github.ayushmaanbhav.productFarm.transformer.GetAttributeByTagTransformer
 import io.github.ayushmaanbhav.productFarm.transformer.GetAttributeTransformer
 import io.github.ayushmaanbhav.productFarm.transformer.GetFunctionalityAttributeTransformer
+import io.github.ayushmaanbhav.productFarm.transformer.RuleTransformer
+import io.github.ayushmaanbhav.productFarm.util.RuleUtil
 import io.github.ayushmaanbhav.productFarm.util.createError
 import io.github.ayushmaanbhav.productFarm.util.dissectAttributeDisplayName
+import io.github.ayushmaanbhav.productFarm.util.generateOriginalDisplayName
 import io.github.ayushmaanbhav.productFarm.util.generatePath
+import jakarta.transaction.Transactional
 import java.util.*
 import org.springframework.http.HttpStatus
 import org.springframework.stereotype.Component
@@ -38,40 +46,203 @@ class AttributeService(
     private val attributeDisplayNameRepo: AttributeDisplayNameRepo,
     private val abstractAttributeTagRepo: AbstractAttributeTagRepo,
     private val productFunctionalityRepo: ProductFunctionalityRepo,
+    private val ruleTransformer: RuleTransformer,
+    private val ruleUtil: RuleUtil,
+    private val tenantAccessService: TenantAccessService,
 ) {
     fun create(productId: String, request: CreateAttributeRequest) {
+        tenantAccessService.checkAccess(productId)
         validateCreateRequest(productId, request)
-        attributeRepo.save(createAttributeTransformer.forward(Pair(productId, request)))
+        val attribute = createAttributeTransformer.forward(Pair(productId, request))
+        request.rule?.let { validateRuleOutputs(attribute.path, it.outputAttributes, mapOf()) }
+            ?.takeIf { it.isNotEmpty() }
+            ?.let { throw ValidatorException(HttpStatus.BAD_REQUEST.value(), it) }
+        attributeRepo.save(attribute)
+    }
+    
+    /**
+     * creates either all of the attributes or none of them, the rules of the batch are validated
+     * together with the existing rules of the product, so that the batch can not introduce a cycle
+     */
+    @Transactional
+    fun bulkCreate(productId: String, requests: List<CreateAttributeRequest>) {
+        tenantAccessService.checkAccess(productId)
+        val errorList = mutableListOf<ErrorDetail>()
+        requests.groupBy { it.displayName }.filterValues { it.size > 1 }.keys.forEach {
+            errorList.add(createError("Attribute is specified more than once: $it"))
+        }
+        val attributes = requests.mapNotNull { request ->
+            try {
+                validateCreateRequest(productId, request)
+                if (request.rule?.outputAttributes?.isEmpty() == true) {
+                    throw ValidatorException(HttpStatus.BAD_REQUEST.value(), listOf(createError("Rule has no output attributes")))
+                }
+                request to createAttributeTransformer.forward(Pair(productId, request))
+            } catch (e: ValidatorException) {
+                val messages = e.errors?.mapNotNull { it.message } ?: listOfNotNull(e.message)
+                messages.forEach { errorList.add(createError("${request.displayName}: $it")) }
+                null
+            }
+        }.let { requestAttributes ->
+            // rules may output attributes created in the same batch
+            val batch = requestAttributes.associate { it.second.path to it.second }
+            requestAttributes.forEach { (request, attribute) ->
+                request.rule?.let { validateRuleOutputs(attribute.path, it.outputAttributes, batch) }
+                    ?.forEach { errorList.add(createError("${request.displayName}: ${it.message}")) }
+            }
+            requestAttributes.map { it.second }
+        }
+        if (errorList.isNotEmpty()) {
+            throw ValidatorException(HttpStatus.BAD_REQUEST.value(), errorList)
+        }
+        // rules are identified by the path of their attribute, the batch replaces existing attributes of the same path
+        val rulesByPath = LinkedHashMap<String, AdhocRule>()
+        (attributeRepo.findAllByProductIdOrderByPathAsc(productId) + attributes).forEach { attribute ->
+            rulesByPath.remove(attribute.path)
+            attribute.rule?.let(ruleTransformer::forward)?.let {
+                rulesByPath[attribute.path] =
+                    AdhocRule(attribute.path, it.type, it.inputAttributes, it.outputAttributes, it.compiledExpression)
+            }
+        }
+        ruleUtil.findMultipleProducers(rulesByPath.values).takeIf { it.isNotEmpty() }?.let { producers ->
+            throw ValidatorException(
+                HttpStatus.BAD_REQUEST.value(),
+                producers.map { createError("Attribute ${it.key} is output by more than one rule: ${it.value.joinToString()}") }
+            )
+        }
+        ruleUtil.findCycle(rulesByPath.values)?.let {
+            throw ValidatorException(
+                HttpStatus.BAD_REQUEST.value(), listOf(createError("Rules contain a cycle: ${it.joinToString(" -> ")}"))
+            )
+        }
+        attributeRepo.saveAll(attributes)
     }
     
     fun get(productId: String, displayName: String): Optional<GetAttributeResponse> {
+        tenantAccessService.checkAccess(productId)
         return attributeDisplayNameRepo.findById(AttributeDisplayNameId(productId, displayName))
             .flatMap { it.path?.let { it1 -> attributeRepo.findById(it1) } }
             .map { getAttributeTransformer.forward(it) }
     }
     
-    fun getFunctionalityAttribute(productId: String, functionality: String): Optional<GetFunctionalityAttributeListResponse> =
-        productFunctionalityRepo.findByProductIdAndName(productId, functionality).map {
-            val attributes = it.requiredAttributes
-                .flatMap { it1 -> attributeRepo.findAllByAbstractAttribute_AbstractPath(it1.id.abstractPath) }
+    fun getFunctionalityAttribute(productId: String, functionality: String): Optional<GetFunctionalityAttributeListResponse> {
+        tenantAccessService.checkAccess(productId)
+        return productFunctionalityRepo.findByProductIdAndName(productId, functionality).map {
+            val attributes = findAllByAbstractPaths(it.requiredAttributes.map { it1 -> it1.id.abstractPath })
             getFunctionalityAttributeTransformer.forward(attributes)
         }
+    }
     
     fun getAttributeByTag(productId: String, tag: String): Optional<GetAttributeListByTagResponse> {
-        return abstractAttributeTagRepo.getByProductIdAndTag(productId, tag)
-            .flatMap { it.id.abstractPath.let { it1 -> attributeRepo.findAllByAbstractAttribute_AbstractPath(it1) } }
+        tenantAccessService.checkAccess(productId)
+        // a tag ending with the namespace separator queries the whole namespace, eg. "pricing:"
+        val abstractAttributeTags = if (tag.endsWith(Constant.TAG_NAMESPACE_SEPARATOR)) {
+            abstractAttributeTagRepo.getByProductIdAndTagStartingWith(productId, tag)
+        } else {
+            abstractAttributeTagRepo.getByProductIdAndTag(productId, tag)
+        }
+        return abstractAttributeTags
+            .map { it.id.abstractPath }
+            .distinct()
+            .let(::findAllByAbstractPaths)
             .map { getAttributeByTagTransformer.forward(it) }
             .let { Optional.of(GetAttributeListByTagResponse(it.toCollection(LinkedHashSet()))) }
     }
     
-    fun clone(parentProductId: String, productId: String) {
-        TODO()
+    /**
+     * copies all attributes of the parent product onto its already cloned abstract attributes,
+     * replacing component types present in remap, rule references are rebuilt for the new product
+     */
+    @Transactional
+    fun clone(parentProductId: String, productId: String, remap: Map<String, String>) {
+        val parentAttributes = attributeRepo.findAllByProductIdOrderByPathAsc(parentProductId)
+        val errorList = mutableListOf<ErrorDetail>()
+        val displayNames = parentAttributes.associate { attribute ->
+            val originalId = attribute.displayNames
+                .firstOrNull { it.displayNameFormat == DisplayNameFormat.ORIGINAL }
+                ?.let { dissectAttributeDisplayName(it.id.displayName) }
+            attribute.path to originalId?.let {
+                val componentType = remap[it.componentType] ?: remap[attribute.abstractAttribute.componentType] ?: it.componentType
+                generateOriginalDisplayName(componentType, it.componentId, it.name)
+            }
+        }
+        displayNames.filterValues { it == null }.keys.forEach { errorList.add(createError("Attribute has no original display name: $it")) }
+        val pathRemap = displayNames.mapNotNull { (path, displayName) ->
+            displayName?.let(::dissectAttributeDisplayName)?.let { path to generatePath(productId, it.componentType, it.componentId, it.name) }
+        }.toMap()
+        val remapPath = { path: String ->
+            pathRemap[path] ?: path.also {
+                if (it.startsWith(parentProductId + Constant.COMPONENT_SEPARATOR)) {
+                    errorList.add(createError("Unmapped reference to parent product attribute: $it"))
+                }
+            }
+        }
+        val requests = parentAttributes.filter { displayNames[it.path] != null }.map { attribute ->
+            CreateAttributeRequest(
+                displayName = displayNames.getValue(attribute.path)!!,
+                value = attribute.value,
+                rule = attribute.rule?.let(ruleTransformer::forward)?.let {
+                    CreateRuleRequest(
+                        type = it.type,
+                        inputAttributes = it.inputAttributes.map(remapPath).toCollection(LinkedHashSet()),
+                        outputAttributes = it.outputAttributes.map(remapPath).toCollection(LinkedHashSet()),
+                        displayExpression = it.displayExpression,
+                        description = it.description,
+                    )
+                },
+                type = attribute.type,
+            )
+        }
+        if (errorList.isNotEmpty()) {
+            throw ValidatorException(HttpStatus.BAD_REQUEST.value(), errorList)
+        }
+        bulkCreate(productId, requests)
+    }
+
+    /**
+     * loads attributes of all abstract paths in a single query,
+     * keeping the order of the given abstract paths
+     */
+    private fun findAllByAbstractPaths(abstractPaths: List<String>): List<Attribute> {
+        if (abstractPaths.isEmpty()) {
+            return listOf()
+        }
+        val attributesByAbstractPath = attributeRepo.findAllByAbstractAttribute_AbstractPathIn(abstractPaths.toSet())
+            .groupBy { it.abstractAttribute.abstractPath }
+        return abstractPaths.flatMap { attributesByAbstractPath[it] ?: listOf() }
     }
 
+    /**
+     * the outputs of the rule of the attribute at path, other than the attribute itself, have to exist either in
+     * the batch or in the product, the batch replaces stored attributes of the same path,
+     * writes to immutable or definition only attributes would be silently ignored, so they are rejected,
+     * abstract attributes in immutableAbstractPaths are treated as immutable
+     */
+    fun validateRuleOutputs(
+        path: String,
+        outputAttributes: Collection<String>,
+        batch: Map<String, Attribute>,
+        immutableAbstractPaths: Set<String> = setOf(),
+    ): List<ErrorDetail> {
+        val otherOutputs = outputAttributes.filterNot { it == path }
+        val existing = otherOutputs.filterNot(batch::containsKey).takeIf { it.isNotEmpty() }
+            ?.let(attributeRepo::findAllById)?.associateBy { it.path } ?: mapOf()
+        return otherOutputs.flatMap { output ->
+            val attribute = batch[output] ?: existing[output]
+                ?: return@flatMap listOf(createError("Rule output attribute does not exist: $output"))
+            listOfNotNull(
+                createError("Rule output attribute is immutable: ${attribute.path}")
+                    .takeIf { attribute.abstractAttribute.immutable || attribute.abstractAttribute.abstractPath in immutableAbstractPaths },
+                createError("Rule output attribute is a definition only attribute: ${attribute.path}")
+                    .takeIf { attribute.type == AttributeValueType.JUST_DEFINITION },
+            )
+        }
+    }
+    
     private fun validateCreateRequest(productId: String, request: CreateAttributeRequest) {
         val dissectedAttributeId = dissectAttributeDisplayName(request.displayName)
             ?: throw ValidatorException(HttpStatus.BAD_REQUEST.value(), "Invalid display name provided")
-        listOf(
+        val abstractPath = listOf(
             generatePath(productId, dissectedAttributeId.componentType, dissectedAttributeId.componentId, dissectedAttributeId.name),
             generatePath(productId, dissectedAttributeId.componentType, null, dissectedAttributeId.name)
         ).firstOrNull(abstractAttributeRepo::existsById)
@@ -98,6 +269,8 @@ class AttributeService(
             }
             AttributeValueType.RULE_DRIVEN -> if ((request.value == null && request.rule != null).not()) {
                 errorList.add(createError("Please specify a value for fixed value attributes."))
+            } else if (abstractAttributeRepo.getReferenceById(abstractPath).immutable) {
+                errorList.add(createError("Rule driven attribute can not be created for an immutable abstract attribute"))
             }
         }
         if (errorList.isNotEmpty()) {
@@ -10,12 +10,14 @@ class CloneProductService(
     private val abstractAttributeService: AbstractAttributeService,
     private val attributeService: AttributeService,
     private val productFunctionalityService: ProductFunctionalityService,
+    private val tenantAccessService: TenantAccessService,
 ) {
     @Transactional
     fun clone(parentProductId: String, request: CloneProductRequest) {
+        tenantAccessService.checkAccess(parentProductId)
         productService.clone(parentProductId, request)
-        abstractAttributeService.clone(parentProductId, request.productId)
-        attributeService.clone(parentProductId, request.productId)
-        productFunctionalityService.clone(parentProductId, request.productId)
+        val abstractPathRemap = abstractAttributeService.clone(parentProductId, request.productId, request.remap ?: mapOf())
+        attributeService.clone(parentProductId, request.productId, request.remap ?: mapOf())
+        productFunctionalityService.clone(parentProductId, request.productId, abstractPathRemap)
     }
 }
@@ -0,0 +1,83 @@
+package io.github.ayushmaanbhav.productFarm.service
+
+import io.github.ayushmaanbhav.common.exception.NonRetryableException
+import io.github.ayushmaanbhav.common.exception.RetryableException
+import io.github.ayushmaanbhav.common.exception.ValidatorException
+import io.github.ayushmaanbhav.productFarm.api.evaluation.dto.EvaluateAdhocRequest
+import io.github.ayushmaanbhav.productFarm.api.evaluation.dto.EvaluateAdhocResponse
+import io.github.ayushmaanbhav.productFarm.model.AdhocRule
+import io.github.ayushmaanbhav.productFarm.util.EvaluationMetrics
+import io.github.ayushmaanbhav.productFarm.util.EvaluationMetrics.Companion.ADHOC_EVALUATION
+import io.github.ayushmaanbhav.productFarm.util.RuleUtil
+import io.github.ayushmaanbhav.productFarm.util.createError
+import io.github.ayushmaanbhav.ruleEngine.algorithm.DependencyGraphBuilder
+import io.github.ayushmaanbhav.ruleEngine.api.EvaluationEngine
+import io.github.ayushmaanbhav.ruleEngine.exception.RuleEngineException
+import io.github.ayushmaanbhav.ruleEngine.model.Query
+import io.github.ayushmaanbhav.ruleEngine.model.QueryType
+import org.springframework.http.HttpStatus
+import org.springframework.stereotype.Component
+
+@Component
+class EvaluationService(
+    private val ruleUtil: RuleUtil,
+    private val evaluationEngine: EvaluationEngine,
+    private val evaluationMetrics: EvaluationMetrics,
+) {
+    /**
+     * evaluates the given rules on the input without persisting anything or using the rule engine cache
+     */
+    fun evaluateAdhoc(request: EvaluateAdhocRequest): EvaluateAdhocResponse =
+        evaluationMetrics.record(ADHOC_EVALUATION) { evaluateAdhocRules(request) }
+    
+    private fun evaluateAdhocRules(request: EvaluateAdhocRequest): EvaluateAdhocResponse {
+        val rules = request.rules.map {
+            AdhocRule(it.id, it.type, it.inputAttributes, it.outputAttributes, ruleUtil.canonicalize(it.expression))
+        }
+        validateAdhocRules(rules)
+        ruleUtil.findCycle(rules)?.let {
+            throw ValidatorException(
+                HttpStatus.BAD_REQUEST.value(), listOf(createError("Rules contain a cycle: ${it.joinToString(" -> ")}"))
+            )
+        }
+        val producedAttributes = rules.flatMap { it.outputAttributes }.toSet()
+        val outputAttributes = request.outputAttributes.ifEmpty { producedAttributes.toList() }
+        outputAttributes.filterNot(producedAttributes::contains).takeIf { it.isNotEmpty() }?.let {
+            throw ValidatorException(HttpStatus.BAD_REQUEST.value(), it.map { path -> createError("No rule produces $path") })
+        }
+        return try {
+            val dependencyGraphBuilder = DependencyGraphBuilder<AdhocRule>()
+            rules.forEach(dependencyGraphBuilder::visit)
+            val executableRules = dependencyGraphBuilder.build()
+                .computeExecutableRules(outputAttributes.distinct().map { Query(it, QueryType.ATTRIBUTE_PATH) })
+                .distinct()
+            val output = evaluationEngine.evaluate(executableRules, request.input)
+            EvaluateAdhocResponse(
+                outputs = output.attributes,
+                provenance = output.provenance,
+                executionPlan = executableRules.map { it.id }.filterNot(output.skippedRules::contains),
+                skippedRules = output.skippedRules,
+                warnings = output.warnings,
+            )
+        } catch (e: NonRetryableException) {
+            throw ValidatorException(HttpStatus.BAD_REQUEST.value(), listOf(createError(e.message ?: "Invalid rules")))
+        } catch (e: RetryableException) {
+            // the server could not run the rules, the request itself may be fine
+            throw ValidatorException(HttpStatus.SERVICE_UNAVAILABLE.value(), listOf(createError(e.message ?: "Rule evaluation unavailable")))
+        } catch (e: RuleEngineException) {
+            throw ValidatorException(HttpStatus.BAD_REQUEST.value(), listOf(createError(e.message ?: "Rule evaluation failed")))
+        }
+    }
+    
+    private fun validateAdhocRules(rules: List<AdhocRule>) {
+        val errors = mutableListOf<String>()
+        rules.groupBy { it.id }.filter { it.key.isBlank() || it.value.size > 1 }
+            .forEach { errors.add("Rule id '${it.key}' should be non blank and unique") }
+        rules.filter { it.outputAttributes.isEmpty() }.forEach { errors.add("Rule ${it.id} has no output attributes") }
+        ruleUtil.findMultipleProducers(rules)
+            .forEach { errors.add("Attribute ${it.key} is output by more than one rule: ${it.value.joinToString()}") }
+        if (errors.isNotEmpty()) {
+            throw ValidatorException(HttpStatus.BAD_REQUEST.value(), errors.map(::createError))
+        }
+    }
+}
@@ -0,0 +1,100 @@
+package io.github.ayushmaanbhav.productFarm.service
+
+import io.github.ayushmaanbhav.productFarm.api.product.dto.EntityDiff
+import io.github.ayushmaanbhav.productFarm.api.product.dto.FieldChange
+import io.github.ayushmaanbhav.productFarm.api.product.dto.ProductDiffResponse
+import io.github.ayushmaanbhav.productFarm.constant.Constant
+import io.github.ayushmaanbhav.productFarm.entity.AbstractAttribute
+import io.github.ayushmaanbhav.productFarm.entity.Attribute
+import io.github.ayushmaanbhav.productFarm.entity.ProductFunctionality
+import io.github.ayushmaanbhav.productFarm.entity.Rule
+import io.github.ayushmaanbhav.productFarm.entity.repository.AbstractAttributeRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.AttributeRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductFunctionalityRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductRepo
+import jakarta.transaction.Transactional
+import java.util.*
+import org.springframework.stereotype.Component
+
+@Component
+class ProductDiffService(
+    private val productRepo: ProductRepo,
+    private val abstractAttributeRepo: AbstractAttributeRepo,
+    private val attributeRepo: AttributeRepo,
+    private val productFunctionalityRepo: ProductFunctionalityRepo,
+    private val tenantAccessService: TenantAccessService,
+) {
+    @Transactional
+    fun diff(leftProductId: String, rightProductId: String): Optional<ProductDiffResponse> {
+        if (!productRepo.existsById(leftProductId) || !productRepo.existsById(rightProductId)) {
+            return Optional.empty()
+        }
+        tenantAccessService.checkAccess(leftProductId)
+        tenantAccessService.checkAccess(rightProductId)
+        return Optional.of(
+            ProductDiffResponse(
+                leftProductId = leftProductId,
+                rightProductId = rightProductId,
+                abstractAttributes = diff(
+                    abstractAttributeRepo.findAllByProductId(leftProductId).associate { it.relativePath() to it.fields() },
+                    abstractAttributeRepo.findAllByProductId(rightProductId).associate { it.relativePath() to it.fields() },
+                ),
+                attributes = diff(
+                    attributeRepo.findAllByProductIdOrderByPathAsc(leftProductId).associate { it.relativePath() to it.fields() },
+                    attributeRepo.findAllByProductIdOrderByPathAsc(rightProductId).associate { it.relativePath() to it.fields() },
+                ),
+                functionalities = diff(
+                    productFunctionalityRepo.findAllByProductId(leftProductId).associate { it.name to it.fields() },
+                    productFunctionalityRepo.findAllByProductId(rightProductId).associate { it.name to it.fields() },
+                ),
+            )
+        )
+    }
+    
+    private fun diff(left: Map<String, Map<String, Any?>>, right: Map<String, Map<String, Any?>>) = EntityDiff(
+        added = right.keys.filterNot(left::containsKey).sorted(),
+        removed = left.keys.filterNot(right::containsKey).sorted(),
+        modified = left.keys.filter(right::containsKey).sorted().associateWith { key ->
+            val leftFields = left.getValue(key)
+            val rightFields = right.getValue(key)
+            // fields like the rule ones may be present on only one side
+            (leftFields.keys + rightFields.keys).filter { leftFields[it] != rightFields[it] }
+                .map { FieldChange(it, leftFields[it], rightFields[it]) }
+        }.filterValues { it.isNotEmpty() },
+    )
+    
+    private fun AbstractAttribute.relativePath() = abstractPath.withoutProductId()
+    
+    private fun Attribute.relativePath() = path.withoutProductId()
+    
+    private fun String.withoutProductId() = substringAfter(Constant.COMPONENT_SEPARATOR)
+    
+    private fun AbstractAttribute.fields(): Map<String, Any?> = linkedMapOf(
+        "datatype" to datatype.name,
+        "enumeration" to enumeration?.name,
+        "tags" to tags.map { it.id.tag },
+        "relatedAttributes" to relatedAttributes.map { "${it.id.relationship}:${it.id.referenceAbstractPath.withoutProductId()}" },
+        "immutable" to immutable,
+        "description" to description,
+    ) + (constraintRule?.fields("constraintRule.") ?: mapOf())
+    
+    private fun Attribute.fields(): Map<String, Any?> = linkedMapOf(
+        "type" to type,
+        "value" to value?.toString(),
+    ) + (rule?.fields("rule.") ?: mapOf())
+    
+    private fun Rule.fields(prefix: String): Map<String, Any?> = linkedMapOf(
+        "${prefix}type" to type,
+        "${prefix}inputAttributes" to inputAttributes.map { it.id.path.withoutProductId() },
+        "${prefix}outputAttributes" to outputAttributes.map { it.id.path.withoutProductId() },
+        "${prefix}expression" to compiledExpression,
+        "${prefix}description" to description,
+    )
+    
+    private fun ProductFunctionality.fields(): Map<String, Any?> = linkedMapOf(
+        "status" to status,
+        "immutable" to immutable,
+        "description" to description,
+        "requiredAttributes" to requiredAttributes.sortedBy { it.order }.map { it.id.abstractPath.withoutProductId() },
+    )
+}
@@ -1,16 +1,26 @@
 package io.github.ayushmaanbhav.productFarm.service
 
+import io.github.ayushmaanbhav.common.exception.NonRetryableException
 import io.github.ayushmaanbhav.common.exception.ValidatorException
 import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.CreateProductFunctionalityRequest
+import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.FunctionalityRequiredAttributeDto
 import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.GetProductFunctionalityResponse
+import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.ProductFunctionalityPrecheckResponse
 import io.github.ayushmaanbhav.productFarm.api.productFunctionality.dto.ProductFunctionalityStatusResponse
+import io.github.ayushmaanbhav.productFarm.constant.AttributeValueType
 import io.github.ayushmaanbhav.productFarm.constant.ProductFunctionalityStatus
+import io.github.ayushmaanbhav.productFarm.entity.ProductFunctionality
 import io.github.ayushmaanbhav.productFarm.entity.repository.AbstractAttributeRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.AttributeRepo
 import io.github.ayushmaanbhav.productFarm.entity.repository.ProductFunctionalityRepo
+import io.github.ayushmaanbhav.productFarm.model.Rule
 import io.github.ayushmaanbhav.productFarm.transformer.CreateProductFunctionalityTransformer
 import io.github.ayushmaanbhav.productFarm.transformer.GetProductFunctionalityStatusTransformer
 import io.github.ayushmaanbhav.productFarm.transformer.GetProductFunctionalityTransformer
+import io.github.ayushmaanbhav.productFarm.transformer.RuleTransformer
+import io.github.ayushmaanbhav.productFarm.util.RuleUtil
 import io.github.ayushmaanbhav.productFarm.util.createError
+import io.github.ayushmaanbhav.ruleEngine.exception.GraphContainsCycleException
 import jakarta.transaction.Transactional
 import java.util.*
 import org.springframework.http.HttpStatus
@@ -19,56 +29,161 @@ import org.springframework.stereotype.Component
 @Component
 class ProductFunctionalityService(
     val abstractAttributeRepo: AbstractAttributeRepo,
+    val attributeRepo: AttributeRepo,
     val productFunctionalityRepo: ProductFunctionalityRepo,
     val createProductFunctionalityTransformer: CreateProductFunctionalityTransformer,
     val getProductFunctionalityTransformer: GetProductFunctionalityTransformer,
     val getProductFunctionalityStatusTransformer: GetProductFunctionalityStatusTransformer,
+    val ruleTransformer: RuleTransformer,
+    val ruleUtil: RuleUtil,
+    val tenantAccessService: TenantAccessService,
+    val attributeService: AttributeService,
 ) {
     @Transactional
     fun create(productId: String, request: CreateProductFunctionalityRequest) {
+        tenantAccessService.checkAccess(productId)
         if (productFunctionalityRepo.existsByProductIdAndName(productId, request.name)) {
             throw ValidatorException(HttpStatus.BAD_REQUEST.value(), listOf(createError("Functionality already exists for this id")))
         }
         productFunctionalityRepo.save(createProductFunctionalityTransformer.forward(Pair(productId, request)))
     }
 
-    fun get(productId: String, name: String): Optional<GetProductFunctionalityResponse> =
-        productFunctionalityRepo.findByProductIdAndName(productId, name).map { getProductFunctionalityTransformer.forward(it) }
+    fun get(productId: String, name: String): Optional<GetProductFunctionalityResponse> {
+        tenantAccessService.checkAccess(productId)
+        return productFunctionalityRepo.findByProductIdAndName(productId, name).map { getProductFunctionalityTransformer.forward(it) }
+    }
 
     @Transactional
-    fun submit(productId: String, name: String): Optional<ProductFunctionalityStatusResponse> =
-        productFunctionalityRepo.findByProductIdAndName(productId, name).map {
+    fun submit(productId: String, name: String): Optional<ProductFunctionalityStatusResponse> {
+        tenantAccessService.checkAccess(productId)
+        return productFunctionalityRepo.findByProductIdAndName(productId, name).map {
             if (it.status != ProductFunctionalityStatus.DRAFT) {
                 throw ValidatorException(HttpStatus.BAD_REQUEST.value(), listOf(createError("Invalid request, not in draft status")))
             }
-            if (it.requiredAttributes.isEmpty()) {
-                throw ValidatorException(HttpStatus.BAD_REQUEST.value(), listOf(createError("No attributes found in functionality")))
-            }
+            checkReadyForApproval(productId, it)
             it.status = ProductFunctionalityStatus.PENDING_APPROVAL
             productFunctionalityRepo.save(it)
             getProductFunctionalityStatusTransformer.forward(it)
         }
+    }
+
+    /**
+     * runs the approval validations without changing any state, so that the issues can be fixed before approving
+     */
+    fun precheck(productId: String, name: String): Optional<ProductFunctionalityPrecheckResponse> {
+        tenantAccessService.checkAccess(productId)
+        return productFunctionalityRepo.findByProductIdAndName(productId, name).map {
+            val errors = validateForApproval(productId, it)
+            ProductFunctionalityPrecheckResponse(status = it.status, ready = errors.isEmpty(), errors = errors)
+        }
+    }
+
+    private fun validateForApproval(productId: String, functionality: ProductFunctionality): List<String> {
+        val errors = mutableListOf<String>()
+        if (functionality.status != ProductFunctionalityStatus.DRAFT
+            && functionality.status != ProductFunctionalityStatus.PENDING_APPROVAL) {
+            errors.add("Not in draft or pending approval status")
+        }
+        if (functionality.requiredAttributes.isEmpty()) {
+            errors.add("No attributes found in functionality")
+        }
+        val abstractPaths = functionality.requiredAttributes.map { it.id.abstractPath }
+        val existingAbstractPaths = abstractAttributeRepo.findAllById(abstractPaths).map { it.abstractPath }.toSet()
+        abstractPaths.filterNot(existingAbstractPaths::contains).forEach { errors.add("Abstract attribute $it does not exist") }
+
+        val attributes = attributeRepo.findAllByAbstractAttribute_AbstractPathIn(existingAbstractPaths)
+            .filter { it.productId == productId }
+        val definedAbstractPaths = attributes.map { it.abstractAttribute.abstractPath }.toSet()
+        existingAbstractPaths.filterNot(definedAbstractPaths::contains)
+            .forEach { errors.add("No attribute defined for abstract attribute $it") }
+        attributes.filter { it.type == AttributeValueType.RULE_DRIVEN }.forEach {
+            val rule = it.rule
+            if (rule == null) {
+                errors.add("No rule found for rule driven attribute ${it.path}")
+            } else if (!compiles(rule)) {
+                errors.add("Rule ${rule.id} for attribute ${it.path} does not compile")
+            }
+        }
+
+        // stored rules must not output attributes which are immutable, or become immutable on approval
+        val immutableAbstractPaths = if (functionality.immutable) existingAbstractPaths else setOf()
+        val productAttributes = attributeRepo.findAllByProductIdOrderByPathAsc(productId)
+        val attributesByPath = productAttributes.associateBy { it.path }
+        val ruleList = LinkedHashSet<Rule>()
+        productAttributes.forEach { attribute ->
+            attribute.rule?.let { storedRule ->
+                val rule = ruleTransformer.forward(storedRule)
+                ruleList.add(rule)
+                attributeService.validateRuleOutputs(attribute.path, rule.outputAttributes, attributesByPath, immutableAbstractPaths)
+                    .forEach { errors.add("Rule ${storedRule.id} for attribute ${attribute.path}: ${it.message}") }
+            }
+        }
+        try {
+            ruleUtil.createRuleDependencyGraph(ruleList)
+        } catch (error: GraphContainsCycleException) {
+            errors.add("rule dependency model contains cycle")
+        } catch (error: NonRetryableException) {
+            // eg. an attribute written by more than one rule
+            errors.add(error.message ?: "rule dependency model is invalid")
+        }
+        return errors
+    }
+
+    private fun checkReadyForApproval(productId: String, functionality: ProductFunctionality) {
+        val errors = validateForApproval(productId, functionality)
+        if (errors.isNotEmpty()) {
+            throw ValidatorException(HttpStatus.BAD_REQUEST.value(), errors.map(::createError))
+        }
+    }
+
+    // the stored expression has to be what its display expression compiles to now
+    private fun compiles(rule: io.github.ayushmaanbhav.productFarm.entity.Rule): Boolean = runCatching {
+        ruleUtil.compileExpression(ruleTransformer.forward(rule)) == ruleUtil.canonicalize(rule.compiledExpression)
+    }.getOrDefault(false)
 
     @Transactional
-    fun approve(productId: String, name: String): Optional<ProductFunctionalityStatusResponse> =
-        productFunctionalityRepo.findByProductIdAndName(productId, name).map {
+    fun approve(productId: String, name: String): Optional<ProductFunctionalityStatusResponse> {
+        tenantAccessService.checkAccess(productId)
+        return productFunctionalityRepo.findByProductIdAndName(productId, name).map {
             if (it.status != ProductFunctionalityStatus.PENDING_APPROVAL) {
                 throw ValidatorException(HttpStatus.BAD_REQUEST.value(), listOf(createError("Not in pending approval status")))
             }
+            checkReadyForApproval(productId, it)
             if (it.immutable) {
-                it.requiredAttributes.map { it1 -> abstractAttributeRepo.getReferenceById(it1.id.abstractPath) }
+                abstractAttributeRepo.findAllById(it.requiredAttributes.map { it1 -> it1.id.abstractPath })
                     .filterNot { it1 -> it1.immutable }
-                    .map { it1 ->
-                        it1.immutable = true
-                        abstractAttributeRepo.save(it1)
-                    }
+                    .onEach { it1 -> it1.immutable = true }
+                    .let(abstractAttributeRepo::saveAll)
             }
             it.status = ProductFunctionalityStatus.ACTIVE
             productFunctionalityRepo.save(it)
             getProductFunctionalityStatusTransformer.forward(it)
         }
+    }
 
-    fun clone(parentProductId: String, productId: String) {
-        TODO()
+    /**
+     * copies all functionalities of the parent product as drafts, pointing them to the cloned abstract attributes
+     */
+    @Transactional
+    fun clone(parentProductId: String, productId: String, abstractPathRemap: Map<String, String>) {
+        val functionalities = productFunctionalityRepo.findAllByProductId(parentProductId)
+        val unmappedPaths = functionalities.flatMap { it.requiredAttributes }.map { it.id.abstractPath }
+            .filterNot(abstractPathRemap::containsKey).distinct()
+        if (unmappedPaths.isNotEmpty()) {
+            throw ValidatorException(
+                HttpStatus.BAD_REQUEST.value(),
+                unmappedPaths.map { createError("Unmapped reference to parent product attribute: $it") }
+            )
+        }
+        functionalities.map { functionality ->
+            CreateProductFunctionalityRequest(
+                name = functionality.name,
+                immutable = functionality.immutable,
+                description = functionality.description,
+                requiredAttributes = functionality.requiredAttributes.sortedBy { it.order }.map {
+                    FunctionalityRequiredAttributeDto(abstractPathRemap.getValue(it.id.abstractPath), it.description)
+                }.toCollection(LinkedHashSet()),
+            )
+        }.forEach { create(productId, it) }
     }
 }
@@ -23,9 +23,11 @@ class ProductService(
     private val getProductTransformer: GetProductTransformer,
     private val productRepo: ProductRepo,
     private val productApprovalService: ProductApprovalService,
+    private val tenantAccessService: TenantAccessService,
 ) {
     @Transactional
     fun create(request: CreateProductRequest) {
+        tenantAccessService.requireTenantId()
         if (productRepo.existsById(request.id)) {
             throw ValidatorException(
                 HttpStatus.BAD_REQUEST.value(), listOf(createError("Product already exists for this id"))
@@ -35,7 +37,10 @@ class ProductService(
     }
     
     fun getProduct(id: String): Optional<GetProductResponse> {
-        return productRepo.findById(id).map { getProductTransformer.forward(it) }
+        return productRepo.findById(id).map {
+            tenantAccessService.checkAccess(it)
+            getProductTransformer.forward(it)
+        }
     }
     
     @Transactional
@@ -45,7 +50,7 @@ class ProductService(
                 HttpStatus.NOT_FOUND.value(), listOf(createError("Product does not exist for this id"))
             )
         }
-        val product = productRepo.getReferenceById(id)
+        val product = productRepo.getReferenceById(id).also(tenantAccessService::checkAccess)
         if (product.status != ProductStatus.DRAFT) {
             throw ValidatorException(
                 HttpStatus.BAD_REQUEST.value(), listOf(createError("Product is not in draft status"))
@@ -63,7 +68,7 @@ class ProductService(
                 HttpStatus.NOT_FOUND.value(), listOf(createError("Product does not exist for this id"))
             )
         }
-        val product = productRepo.getReferenceById(id)
+        val product = productRepo.getReferenceById(id).also(tenantAccessService::checkAccess)
         if (product.status != ProductStatus.PENDING_APPROVAL) {
             throw ValidatorException(
                 HttpStatus.BAD_REQUEST.value(), listOf(createError("Product is not in pending approval status"))
@@ -75,7 +80,7 @@ class ProductService(
                     HttpStatus.NOT_FOUND.value(), listOf(createError("Product to discontinue does not exist for id"))
                 )
             }
-            val productToDiscontinue = productRepo.getReferenceById(it)
+            val productToDiscontinue = productRepo.getReferenceById(it).also(tenantAccessService::checkAccess)
             if (productToDiscontinue.status != ProductStatus.ACTIVE) {
                 throw ValidatorException(
                     HttpStatus.BAD_REQUEST.value(), listOf(createError("Product to discontinue not in active status"))
@@ -109,13 +114,13 @@ class ProductService(
                 HttpStatus.NOT_FOUND.value(), listOf(createError("Product does not exist for this id"))
             )
         }
-        val parentProduct = productRepo.getReferenceById(parentProductId)
+        val parentProduct = productRepo.getReferenceById(parentProductId).also(tenantAccessService::checkAccess)
         val createProductRequest = CreateProductRequest(
             id = request.productId,
             name = request.name,
             effectiveFrom = request.effectiveFrom,
             expiryAt = request.expiryAt,
-            templateType = parentProduct.templateType,
+            templateType = request.templateType ?: parentProduct.templateType,
             description = request.description,
         )
         create(createProductRequest)
@@ -2,12 +2,17 @@ package io.github.ayushmaanbhav.productFarm.service
 
 import io.github.ayushmaanbhav.common.exception.ValidatorException
 import io.github.ayushmaanbhav.productFarm.api.productTemplate.dto.ProductTemplateEnumerationDto
+import io.github.ayushmaanbhav.productFarm.api.productTemplate.dto.ProductTemplateEnumerationListResponse
 import io.github.ayushmaanbhav.productFarm.constant.ProductTemplateType
 import io.github.ayushmaanbhav.productFarm.entity.repository.ProductTemplateEnumerationRepo
 import io.github.ayushmaanbhav.productFarm.transformer.ProductTemplateEnumerationTransformer
 import io.github.ayushmaanbhav.productFarm.util.createError
+import io.github.ayushmaanbhav.productFarm.util.decodePageToken
+import io.github.ayushmaanbhav.productFarm.util.encodePageToken
+import io.github.ayushmaanbhav.productFarm.util.validatePageSize
 import jakarta.transaction.Transactional
 import java.util.*
+import org.springframework.data.domain.PageRequest
 import org.springframework.http.HttpStatus
 import org.springframework.stereotype.Component
 
@@ -26,6 +31,23 @@ class ProductTemplateService(
         enumerationRepo.save(enumerationTransformer.reverse(Pair(enumerationDto, templateType)))
     }
     
+    // enumerations are ordered by name, values keep the order they were created with
+    fun listEnumerations(
+        templateType: ProductTemplateType, pageToken: String?, pageSize: Int
+    ): ProductTemplateEnumerationListResponse {
+        validatePageSize(pageSize)
+        // fetch one extra to know if there is a next page
+        val pageable = PageRequest.of(0, pageSize + 1)
+        val enumerations = pageToken?.let {
+            enumerationRepo.findAllByProductTemplateTypeAndNameGreaterThanOrderByNameAsc(templateType, decodePageToken(it), pageable)
+        } ?: enumerationRepo.findAllByProductTemplateTypeOrderByNameAsc(templateType, pageable)
+        val page = enumerations.take(pageSize)
+        return ProductTemplateEnumerationListResponse(
+            enumerations = page.map { enumerationTransformer.forward(it).first },
+            nextPageToken = if (enumerations.size > pageSize) encodePageToken(page.last().name) else null,
+        )
+    }
+    
     fun getEnumeration(templateType: ProductTemplateType, name: String): Optional<ProductTemplateEnumerationDto> {
         return enumerationRepo.findByProductTemplateTypeAndName(templateType, name).map {
             enumerationTransformer.forward(it).first
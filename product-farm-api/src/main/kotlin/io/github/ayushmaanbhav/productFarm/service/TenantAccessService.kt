@@ -0,0 +1,44 @@
+package io.github.ayushmaanbhav.productFarm.service
+
+import io.github.ayushmaanbhav.common.exception.ValidatorException
+import io.github.ayushmaanbhav.common.model.RequestMetadata
+import io.github.ayushmaanbhav.productFarm.entity.Product
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductRepo
+import io.github.ayushmaanbhav.productFarm.util.createError
+import org.springframework.http.HttpStatus
+import org.springframework.stereotype.Component
+
+/**
+ * products belong to the tenant of the request that created them, every entry point taking
+ * a product id checks the access before reading or writing anything of the product,
+ * product ids are still unique across tenants, so a tenant can not reuse the id of another tenant's product
+ */
+@Component
+class TenantAccessService(
+    private val productRepo: ProductRepo,
+) {
+    // unknown products are left to the caller, so that it can respond with its usual not found error
+    fun checkAccess(productId: String) {
+        productRepo.findById(productId).ifPresent(::checkAccess)
+    }
+
+    fun checkAccess(product: Product) {
+        val tenantId = requireTenantId()
+        // products created before tenants were introduced belong to no tenant, and can not be accessed
+        if (product.tenantId == null) {
+            throw ValidatorException(
+                HttpStatus.FORBIDDEN.value(), listOf(createError("Product is not assigned to any tenant"))
+            )
+        }
+        if (product.tenantId != tenantId) {
+            throw ValidatorException(
+                HttpStatus.FORBIDDEN.value(), listOf(createError("Product does not belong to this tenant"))
+            )
+        }
+    }
+    
+    fun requireTenantId(): String = RequestMetadata.getTenantId()
+        ?: throw ValidatorException(
+            HttpStatus.BAD_REQUEST.value(), listOf(createError("${RequestMetadata.TENANT_ID_HEADER} header is required"))
+        )
+}
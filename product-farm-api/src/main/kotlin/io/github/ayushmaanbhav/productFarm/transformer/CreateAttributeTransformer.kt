@@ -23,7 +23,7 @@ class CreateAttributeTransformer(
         val abstractAttribute = listOf(
             generatePath(productId, dissectedId.componentType, dissectedId.componentId, dissectedId.name),
             generatePath(productId, dissectedId.componentType, null, dissectedId.name)
-        ).map(abstractAttributeRepo::getReferenceById).first()
+        ).first(abstractAttributeRepo::existsById).let(abstractAttributeRepo::getReferenceById)
         val path = generatePath(productId, dissectedId.componentType, dissectedId.componentId, dissectedId.name)
         val displayNames = generateDisplayNames(productId, dissectedId.componentType, dissectedId.componentId, dissectedId.name)
         return Attribute(
@@ -1,5 +1,6 @@
 package io.github.ayushmaanbhav.productFarm.transformer
 
+import io.github.ayushmaanbhav.common.model.RequestMetadata
 import io.github.ayushmaanbhav.productFarm.api.product.dto.CreateProductRequest
 import io.github.ayushmaanbhav.productFarm.constant.ProductStatus
 import io.github.ayushmaanbhav.productFarm.entity.Product
@@ -18,5 +19,6 @@ class CreateProductTransformer : OneWayTransformer<CreateProductRequest, Product
             templateType = input.templateType,
             parentProductId = null,
             description = input.description,
+            tenantId = RequestMetadata.getTenantId(),
         )
 }
@@ -0,0 +1,39 @@
+package io.github.ayushmaanbhav.productFarm.util
+
+import io.micrometer.core.instrument.MeterRegistry
+import io.micrometer.core.instrument.Timer
+import org.springframework.stereotype.Component
+
+/**
+ * times evaluations by type and outcome, and counts the failed ones by error
+ */
+@Component
+class EvaluationMetrics(private val meterRegistry: MeterRegistry) {
+    fun <T> record(type: String, evaluation: () -> T): T {
+        val sample = Timer.start(meterRegistry)
+        var outcome = OUTCOME_FAILURE
+        try {
+            return evaluation().also { outcome = OUTCOME_SUCCESS }
+        } catch (e: Exception) {
+            meterRegistry.counter(EVALUATION_ERROR_METRIC, TYPE_TAG, type, ERROR_TAG, e.javaClass.simpleName).increment()
+            throw e
+        } finally {
+            sample.stop(
+                Timer.builder(EVALUATION_METRIC).tags(TYPE_TAG, type, OUTCOME_TAG, outcome)
+                    .publishPercentileHistogram().register(meterRegistry)
+            )
+        }
+    }
+    
+    companion object {
+        const val EVALUATION_METRIC = "product.farm.evaluation"
+        const val EVALUATION_ERROR_METRIC = "product.farm.evaluation.errors"
+        const val TYPE_TAG = "type"
+        const val ADHOC_EVALUATION = "adhoc"
+        const val CONSTRAINT_EVALUATION = "constraint"
+        private const val OUTCOME_TAG = "outcome"
+        private const val ERROR_TAG = "error"
+        private const val OUTCOME_SUCCESS = "success"
+        private const val OUTCOME_FAILURE = "failure"
+    }
+}
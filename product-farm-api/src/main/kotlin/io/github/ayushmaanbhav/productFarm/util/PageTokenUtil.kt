@@ -0,0 +1,25 @@
+package io.github.ayushmaanbhav.productFarm.util
+
+import io.github.ayushmaanbhav.common.exception.ValidatorException
+import io.github.ayushmaanbhav.productFarm.constant.Constant
+import org.springframework.http.HttpStatus
+import java.util.*
+
+/**
+ * page tokens are opaque cursors holding the sort key of the last item of the previous page,
+ * so that the next page starts after it even if items are added or removed in between
+ */
+fun encodePageToken(lastSortKey: String): String =
+    Base64.getUrlEncoder().withoutPadding().encodeToString(lastSortKey.toByteArray(Charsets.UTF_8))
+
+fun decodePageToken(pageToken: String): String =
+    runCatching { String(Base64.getUrlDecoder().decode(pageToken), Charsets.UTF_8) }
+        .getOrElse { throw ValidatorException(HttpStatus.BAD_REQUEST.value(), listOf(createError("Invalid page token"))) }
+
+fun validatePageSize(pageSize: Int) {
+    if (pageSize < 1 || pageSize > Constant.MAX_PAGE_SIZE) {
+        throw ValidatorException(
+            HttpStatus.BAD_REQUEST.value(), listOf(createError("Page size should be between 1 and ${Constant.MAX_PAGE_SIZE}"))
+        )
+    }
+}
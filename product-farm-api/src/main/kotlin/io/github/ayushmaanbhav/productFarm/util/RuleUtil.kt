@@ -1,36 +1,29 @@
 package io.github.ayushmaanbhav.productFarm.util
 
+import com.fasterxml.jackson.core.type.TypeReference
 import com.fasterxml.jackson.databind.JsonNode
 import com.fasterxml.jackson.databind.ObjectMapper
+import com.fasterxml.jackson.databind.node.JsonNodeFactory
 import io.github.ayushmaanbhav.common.exception.ValidatorException
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
+import io.github.ayushmaanbhav.productFarm.util.EvaluationMetrics.Companion.CONSTRAINT_EVALUATION
 import io.github.ayushmaanbhav.ruleEngine.algorithm.AcyclicDirectedGraph
 import io.github.ayushmaanbhav.ruleEngine.algorithm.DependencyGraphBuilder
-import io.github.ayushmaanbhav.ruleEngine.api.RuleEngine
-import io.github.ayushmaanbhav.ruleEngine.model.Query
-import io.github.ayushmaanbhav.ruleEngine.model.QueryContext
-import io.github.ayushmaanbhav.ruleEngine.model.QueryInput
-import io.github.ayushmaanbhav.ruleEngine.model.QueryType
 import io.github.ayushmaanbhav.ruleEngine.model.rule.Rule
-import java.util.*
 import org.springframework.http.HttpStatus.BAD_REQUEST
 import org.springframework.stereotype.Component
 
 @Component
 class RuleUtil(
-    val productRuleEngine: RuleEngine,
     val objectMapper: ObjectMapper,
+    val jsonLogicEngine: JsonLogicEngine,
+    private val evaluationMetrics: EvaluationMetrics,
 ) {
     fun compileExpression(input: io.github.ayushmaanbhav.productFarm.model.Rule): String {
         // can implement custom expression compilation/parsing here
         return when {
-            input.displayExpression.expression != null -> {
-                try {
-                    objectMapper.readTree(input.displayExpression.expression) // try parsing for validation
-                } catch (e: Exception) {
-                    throw ValidatorException(BAD_REQUEST.value(), listOf(createError("unknown display expression type, all values null")))
-                }
-                input.displayExpression.expression
-            }
+            input.displayExpression.expression != null -> canonicalize(input.displayExpression.expression)
             input.displayExpression.slab != null -> {
                 // TODO()
                 throw NotImplementedError("slab compilation not implemented")
@@ -39,17 +32,127 @@ class RuleUtil(
         }
     }
     
+    /**
+     * rewrites the json with sorted object keys, numbers without trailing zeros and no whitespace,
+     * so that logically identical expressions are stored as identical strings
+     */
+    fun canonicalize(json: String): String {
+        val tree = try {
+            objectMapper.readTree(json)
+        } catch (e: Exception) {
+            throw ValidatorException(BAD_REQUEST.value(), listOf(createError("expression is not valid json")))
+        }
+        return objectMapper.writeValueAsString(canonicalize(tree))
+    }
+    
+    private fun canonicalize(node: JsonNode): JsonNode = when {
+        node.isObject -> JsonNodeFactory.instance.objectNode().also { canonicalNode ->
+            node.fieldNames().asSequence().sorted().forEach { canonicalNode.set<JsonNode>(it, canonicalize(node[it])) }
+        }
+        node.isArray -> JsonNodeFactory.instance.arrayNode().also { canonicalNode ->
+            node.forEach { canonicalNode.add(canonicalize(it)) }
+        }
+        node.isNumber -> node.decimalValue().stripTrailingZeros().let {
+            if (it.scale() !in -MAX_NUMBER_SCALE..MAX_NUMBER_SCALE) {
+                throw ValidatorException(BAD_REQUEST.value(), listOf(createError("expression contains a number out of range: $it")))
+            }
+            // large integral numbers stay decimal, so that their digits are never expanded
+            if (it.scale() <= 0 && it.precision() - it.scale() <= MAX_INTEGER_DIGITS) {
+                JsonNodeFactory.instance.numberNode(it.toBigIntegerExact())
+            } else {
+                JsonNodeFactory.instance.numberNode(it)
+            }
+        }
+        else -> node
+    }
+    
     fun <R : Rule> createRuleDependencyGraph(ruleList: LinkedHashSet<R>): AcyclicDirectedGraph<R> {
         val graphBuilder = DependencyGraphBuilder<R>()
         ruleList.forEach { graphBuilder.visit(it) }
         return graphBuilder.build().getGraph()
     }
     
-    fun <R : Rule> executeConstraint(rule: R, input: JsonNode): Boolean {
-        val context = QueryContext(UUID.randomUUID().toString(), listOf(rule))
-        val ruleInput = QueryInput(linkedMapOf(Pair("value", input)))
-        val queries = listOf(Query(rule.ruleType(), QueryType.RULE_TYPE))
-        val ruleOutput = productRuleEngine.evaluate(context, queries, ruleInput)
-        return ruleOutput.attributes["valid"]?.toString()?.toBooleanStrictOrNull() ?: false
+    /**
+     * returns the ids of the rules producing each attribute output by more than one rule
+     */
+    fun <R : Rule> findMultipleProducers(rules: Collection<R>): Map<String, List<String>> =
+        rules.flatMap { rule -> rule.getOutputAttributePaths().map { it to rule.getId() } }
+            .groupBy({ it.first }, { it.second })
+            .filterValues { it.size > 1 }
+    
+    /**
+     * returns the ids of the rules forming a cycle, where each rule depends on an output of the next one,
+     * rule ids must be stable across calls and each attribute must have a single producer, see findMultipleProducers
+     */
+    fun <R : Rule> findCycle(rules: Collection<R>): List<String>? {
+        val producerByOutput = rules.flatMap { rule -> rule.getOutputAttributePaths().map { it to rule } }.toMap()
+        val visited = HashSet<String>()
+        val stack = LinkedHashSet<String>()
+        
+        fun visit(rule: R): List<String>? {
+            if (stack.contains(rule.getId())) {
+                return stack.dropWhile { it != rule.getId() } + rule.getId()
+            }
+            if (!visited.add(rule.getId())) {
+                return null
+            }
+            stack.add(rule.getId())
+            rule.getInputAttributePaths().mapNotNull(producerByOutput::get).forEach { dependency ->
+                visit(dependency)?.let { return it }
+            }
+            stack.remove(rule.getId())
+            return null
+        }
+        
+        return rules.firstNotNullOfOrNull(::visit)
+    }
+    
+    /**
+     * evaluates the constraint expression with the value to validate under "value", along with the given context,
+     * the expression either returns a boolean or the rule output object with a "valid" attribute
+     */
+    fun executeConstraint(
+        rule: io.github.ayushmaanbhav.productFarm.model.Rule, input: JsonNode, context: Map<String, Any?> = mapOf()
+    ): Boolean = evaluationMetrics.record(CONSTRAINT_EVALUATION) { evaluateConstraint(rule, input, context) }
+    
+    private fun evaluateConstraint(
+        rule: io.github.ayushmaanbhav.productFarm.model.Rule, input: JsonNode, context: Map<String, Any?>
+    ): Boolean {
+        val ruleName = rule.description ?: "constraint rule"
+        val expression = runCatching { objectMapper.readValue(rule.compiledExpression, mapTypeReference) }
+            .getOrElse {
+                throw ValidatorException(BAD_REQUEST.value(), listOf(createError("$ruleName: expression is not valid json")))
+            }
+        val data = LinkedHashMap(context)
+        data[CONSTRAINT_VALUE] = objectMapper.convertValue(input, Any::class.java)
+        return when (val result = jsonLogicEngine.evaluate(expression, data)) {
+            is JsonLogicResult.Success -> isConstraintSatisfied(result.value)
+            is JsonLogicResult.Failure.NullResult -> false
+            is JsonLogicResult.Failure.MissingVariable -> throw ValidatorException(
+                BAD_REQUEST.value(), listOf(createError("$ruleName: could not be evaluated, missing variable ${result.path}"))
+            )
+            is JsonLogicResult.Failure.InvalidDate -> throw ValidatorException(
+                BAD_REQUEST.value(), listOf(createError("$ruleName: could not be evaluated, invalid date ${result.value}"))
+            )
+            is JsonLogicResult.Failure -> throw ValidatorException(
+                BAD_REQUEST.value(), listOf(createError("$ruleName: could not be evaluated, ${result.javaClass.simpleName}"))
+            )
+        }
+    }
+    
+    // rules store their outputs as an object, so constraint rules return {"valid": ...}
+    private fun isConstraintSatisfied(output: Any?): Boolean = when (output) {
+        is Boolean -> output
+        is Map<*, *> -> isConstraintSatisfied(output[CONSTRAINT_OUTPUT])
+        else -> output?.toString()?.toBooleanStrictOrNull() ?: false
+    }
+    
+    companion object {
+        const val CONSTRAINT_VALUE = "value"
+        const val CONSTRAINT_OUTPUT = "valid"
+        // the largest scale jackson writes as a plain number
+        private const val MAX_NUMBER_SCALE = 9999
+        private const val MAX_INTEGER_DIGITS = 64
+        private val mapTypeReference = object : TypeReference<LinkedHashMap<String, Any?>>() {}
     }
 }
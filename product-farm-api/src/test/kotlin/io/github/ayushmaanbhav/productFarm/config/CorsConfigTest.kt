@@ -0,0 +1,102 @@
+package io.github.ayushmaanbhav.productFarm.config
+
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.core.spec.style.StringSpec
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.string.shouldContain
+import org.springframework.http.HttpHeaders
+import org.springframework.http.HttpMethod
+import org.springframework.http.HttpStatus
+import org.springframework.mock.web.MockHttpServletRequest
+import org.springframework.mock.web.MockHttpServletResponse
+import org.springframework.web.cors.DefaultCorsProcessor
+import org.springframework.web.servlet.config.annotation.CorsRegistry
+
+class CorsConfigTest : StringSpec() {
+    private fun corsConfig(allowedOrigins: List<String>, allowCredentials: Boolean, devMode: Boolean = false) = CorsConfig(
+        allowedOrigins = allowedOrigins,
+        allowedMethods = listOf("GET", "POST"),
+        allowedHeaders = listOf("*"),
+        allowCredentials = allowCredentials,
+        maxAge = 1800,
+        devMode = devMode,
+    )
+
+    private fun preflight(corsConfig: CorsConfig, origin: String): MockHttpServletResponse {
+        val registry = object : CorsRegistry() {
+            fun configurations() = corsConfigurations
+        }
+        corsConfig.addCorsMappings(registry)
+        val request = MockHttpServletRequest(HttpMethod.OPTIONS.name(), "/product").apply {
+            addHeader(HttpHeaders.ORIGIN, origin)
+            addHeader(HttpHeaders.ACCESS_CONTROL_REQUEST_METHOD, HttpMethod.POST.name())
+        }
+        return MockHttpServletResponse().also { DefaultCorsProcessor().processRequest(registry.configurations()["/**"], request, it) }
+    }
+
+    init {
+        "preflight should allow a configured origin" {
+            // Act
+            val response = preflight(corsConfig(listOf("https://app.example.com"), allowCredentials = true), "https://app.example.com")
+
+            // Assert
+            response.status shouldBe HttpStatus.OK.value()
+            response.getHeader(HttpHeaders.ACCESS_CONTROL_ALLOW_ORIGIN) shouldBe "https://app.example.com"
+            response.getHeader(HttpHeaders.ACCESS_CONTROL_ALLOW_CREDENTIALS) shouldBe "true"
+            response.getHeader(HttpHeaders.ACCESS_CONTROL_ALLOW_METHODS)!! shouldContain HttpMethod.POST.name()
+            response.getHeader(HttpHeaders.ACCESS_CONTROL_MAX_AGE) shouldBe "1800"
+        }
+
+        "preflight should reject an origin which is not configured" {
+            // Act
+            val response = preflight(corsConfig(listOf("https://app.example.com"), allowCredentials = true), "https://evil.example.com")
+
+            // Assert
+            response.status shouldBe HttpStatus.FORBIDDEN.value()
+            response.getHeader(HttpHeaders.ACCESS_CONTROL_ALLOW_ORIGIN) shouldBe null
+        }
+
+        "CorsConfig should reject any origin with credentials outside dev mode" {
+            // Act
+            val exception = shouldThrow<IllegalArgumentException> { corsConfig(listOf(CorsConfig.ANY_ORIGIN), allowCredentials = true) }
+
+            // Assert
+            exception.message!! shouldContain "cors.dev-mode"
+        }
+
+        "CorsConfig should reject a wildcard origin pattern with credentials outside dev mode" {
+            // Act
+            val exception = shouldThrow<IllegalArgumentException> { corsConfig(listOf("https://*"), allowCredentials = true) }
+
+            // Assert
+            exception.message!! shouldContain "cors.dev-mode"
+        }
+
+        "preflight should not allow any origin when none are configured outside dev mode" {
+            // Act
+            val response = preflight(corsConfig(listOf(), allowCredentials = false), "https://evil.example.com")
+
+            // Assert
+            response.getHeader(HttpHeaders.ACCESS_CONTROL_ALLOW_ORIGIN) shouldBe null
+        }
+
+        "preflight should allow any origin when none are configured in dev mode" {
+            // Act
+            val response = preflight(corsConfig(listOf(), allowCredentials = false, devMode = true), "http://localhost:3000")
+
+            // Assert
+            response.status shouldBe HttpStatus.OK.value()
+            response.getHeader(HttpHeaders.ACCESS_CONTROL_ALLOW_ORIGIN) shouldBe "http://localhost:3000"
+        }
+
+        "CorsConfig should allow any origin with credentials in dev mode" {
+            // Act
+            val response = preflight(
+                corsConfig(listOf(CorsConfig.ANY_ORIGIN), allowCredentials = true, devMode = true), "http://localhost:3000"
+            )
+
+            // Assert
+            response.getHeader(HttpHeaders.ACCESS_CONTROL_ALLOW_ORIGIN) shouldBe "http://localhost:3000"
+        }
+    }
+}
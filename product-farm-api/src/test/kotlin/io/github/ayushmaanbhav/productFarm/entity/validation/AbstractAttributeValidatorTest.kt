@@ -0,0 +1,42 @@
+package io.github.ayushmaanbhav.productFarm.entity.validation
+
+import io.github.ayushmaanbhav.productFarm.entity.compositeId.AbstractAttributeTagId
+import io.github.ayushmaanbhav.productFarm.entity.relationship.AbstractAttributeTag
+import io.kotest.core.spec.style.StringSpec
+import io.kotest.matchers.shouldBe
+import io.mockk.mockk
+
+class AbstractAttributeValidatorTest : StringSpec() {
+    private val abstractAttributeValidator = AbstractAttributeValidator(mockk(), mockk(), mockk(), mockk(), mockk())
+
+    private fun tags(vararg tags: String) = tags.mapIndexed { index, tag ->
+        AbstractAttributeTag(AbstractAttributeTagId(ABSTRACT_PATH, tag), PRODUCT, index)
+    }
+
+    init {
+        "isValidTags should accept plain and namespaced tags" {
+            // Act & Assert
+            abstractAttributeValidator.isValidTags(tags("premium", "pricing:discount", "pricing:loading:extra-risk")) shouldBe true
+        }
+
+        "isValidTags should reject a tag which does not match the tag pattern even when the order is valid" {
+            // Act & Assert
+            abstractAttributeValidator.isValidTags(tags("premium", "Pricing")) shouldBe false
+            abstractAttributeValidator.isValidTags(tags("pricing:")) shouldBe false
+            abstractAttributeValidator.isValidTags(tags("pricing::discount")) shouldBe false
+        }
+
+        "isValidTags should reject tags out of order" {
+            // Arrange
+            val tags = tags("premium", "pricing:discount").reversed()
+
+            // Act & Assert
+            abstractAttributeValidator.isValidTags(tags) shouldBe false
+        }
+    }
+
+    companion object {
+        private const val PRODUCT = "product"
+        private const val ABSTRACT_PATH = "product:abstract-path:cover:premium"
+    }
+}
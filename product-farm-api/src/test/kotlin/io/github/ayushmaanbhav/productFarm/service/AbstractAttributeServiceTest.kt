@@ -0,0 +1,126 @@
+package io.github.ayushmaanbhav.productFarm.service
+
+import io.github.ayushmaanbhav.common.exception.ValidatorException
+import io.github.ayushmaanbhav.common.model.RequestMetadata
+import io.github.ayushmaanbhav.productFarm.api.attribute.dto.CreateAbstractAttributeRequest
+import io.github.ayushmaanbhav.productFarm.constant.AttributeRelationshipType
+import io.github.ayushmaanbhav.productFarm.constant.DatatypeType
+import io.github.ayushmaanbhav.productFarm.entity.AbstractAttribute
+import io.github.ayushmaanbhav.productFarm.entity.Datatype
+import io.github.ayushmaanbhav.productFarm.entity.compositeId.AbstractAttributeRelatedAttributeId
+import io.github.ayushmaanbhav.productFarm.entity.compositeId.AttributeDisplayNameId
+import io.github.ayushmaanbhav.productFarm.entity.relationship.AbstractAttributeRelatedAttribute
+import io.github.ayushmaanbhav.productFarm.entity.relationship.AttributeDisplayName
+import io.github.ayushmaanbhav.productFarm.entity.repository.AbstractAttributeRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.DatatypeRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductRepo
+import io.github.ayushmaanbhav.productFarm.service.ProductServiceTest.Companion.product
+import io.github.ayushmaanbhav.productFarm.transformer.CreateAbstractAttributeTransformer
+import io.github.ayushmaanbhav.productFarm.util.generateDisplayNames
+import io.github.ayushmaanbhav.productFarm.util.generatePath
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.core.spec.style.StringSpec
+import io.kotest.matchers.shouldBe
+import io.mockk.clearAllMocks
+import io.mockk.every
+import io.mockk.mockk
+import io.mockk.verify
+
+class AbstractAttributeServiceTest : StringSpec() {
+    private val abstractAttributeRepo = mockk<AbstractAttributeRepo>()
+    private val datatypeRepo = mockk<DatatypeRepo>()
+    private val productRepo = mockk<ProductRepo>()
+    private val createAbstractAttributeTransformer = mockk<CreateAbstractAttributeTransformer>()
+    private val abstractAttributeService = AbstractAttributeService(
+        createAbstractAttributeTransformer = createAbstractAttributeTransformer,
+        getAbstractAttributeTransformer = mockk(),
+        abstractAttributeRepo = abstractAttributeRepo,
+        datatypeRepo = datatypeRepo,
+        productRepo = productRepo,
+        enumerationRepo = mockk(),
+        attributeDisplayNameRepo = mockk(),
+        ruleTransformer = mockk(),
+        tenantAccessService = TenantAccessService(productRepo),
+    )
+
+    private val sumAssuredPath = generatePath(PARENT, "Cover", null, "sumAssured")
+    private val premiumPath = generatePath(PARENT, "Cover", null, "premium")
+
+    init {
+        beforeTest {
+            clearAllMocks()
+            every { abstractAttributeRepo.findAllByProductId(PARENT) } returns listOf(
+                abstractAttribute("Cover", "sumAssured"),
+                abstractAttribute("Cover", "premium", relatedAbstractPath = sumAssuredPath),
+            )
+            every { datatypeRepo.existsById(any()) } returns true
+            RequestMetadata.setTenantId("tenantA")
+            every { productRepo.getReferenceById(CHILD) } returns product(CHILD, "tenantA")
+            every { abstractAttributeRepo.saveAll(any<List<AbstractAttribute>>()) } answers { firstArg() }
+        }
+        afterTest { RequestMetadata.resetTenantId() }
+
+        "clone should rebuild paths and references with the remapped component type" {
+            // Arrange
+            val requests = mutableListOf<Pair<String, CreateAbstractAttributeRequest>>()
+            every { createAbstractAttributeTransformer.forward(capture(requests)) } returns mockk()
+
+            // Act
+            val pathRemap = abstractAttributeService.clone(PARENT, CHILD, mapOf("Cover" to "Benefit"))
+
+            // Assert
+            val newSumAssuredPath = generatePath(CHILD, "Benefit", null, "sumAssured")
+            pathRemap shouldBe mapOf(
+                sumAssuredPath to newSumAssuredPath,
+                premiumPath to generatePath(CHILD, "Benefit", null, "premium"),
+            )
+            requests.map { it.first }.distinct() shouldBe listOf(CHILD)
+            requests.map { it.second.componentType }.distinct() shouldBe listOf("Benefit")
+            requests.map { it.second.name } shouldBe listOf("sumAssured", "premium")
+            requests[1].second.relatedAttributes shouldBe linkedSetOf(newSumAssuredPath)
+        }
+
+        "clone should reject a remapped component type which is not valid" {
+            // Arrange
+            every { createAbstractAttributeTransformer.forward(any()) } returns mockk()
+
+            // Act
+            val exception = shouldThrow<ValidatorException> {
+                abstractAttributeService.clone(PARENT, CHILD, mapOf("Cover" to "benefit-type"))
+            }
+
+            // Assert
+            exception.errors!!.map { it.message } shouldBe listOf("Please enter a valid componentType: benefit-type")
+            verify(exactly = 0) { abstractAttributeRepo.saveAll(any<List<AbstractAttribute>>()) }
+        }
+    }
+
+    private fun abstractAttribute(componentType: String, name: String, relatedAbstractPath: String? = null): AbstractAttribute {
+        val abstractPath = generatePath(PARENT, componentType, null, name)
+        return AbstractAttribute(
+            abstractPath = abstractPath,
+            displayNames = generateDisplayNames(PARENT, componentType, null, name).mapIndexed { index, displayName ->
+                AttributeDisplayName(AttributeDisplayNameId(PARENT, displayName.second), abstractPath, null, displayName.first, index)
+            },
+            componentType = componentType.lowercase(),
+            componentId = null,
+            tags = listOf(),
+            datatype = Datatype("decimal", DatatypeType.NUMBER, null),
+            enumeration = null,
+            relatedAttributes = listOfNotNull(relatedAbstractPath).map {
+                AbstractAttributeRelatedAttribute(
+                    AbstractAttributeRelatedAttributeId(abstractPath, it, AttributeRelationshipType.enumeration.name), 0
+                )
+            },
+            constraintRule = null,
+            immutable = false,
+            description = null,
+            productId = PARENT,
+        )
+    }
+
+    companion object {
+        private const val PARENT = "parent"
+        private const val CHILD = "child"
+    }
+}
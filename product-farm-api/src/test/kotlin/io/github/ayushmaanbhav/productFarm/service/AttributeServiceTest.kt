@@ -0,0 +1,361 @@
+package io.github.ayushmaanbhav.productFarm.service
+
+import com.fasterxml.jackson.databind.node.IntNode
+import io.github.ayushmaanbhav.common.exception.ValidatorException
+import io.github.ayushmaanbhav.common.model.RequestMetadata
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.productFarm.api.attribute.dto.CreateAttributeRequest
+import io.github.ayushmaanbhav.productFarm.api.attribute.dto.CreateRuleRequest
+import io.github.ayushmaanbhav.productFarm.constant.AttributeValueType
+import io.github.ayushmaanbhav.productFarm.constant.ProductFunctionalityStatus
+import io.github.ayushmaanbhav.productFarm.entity.AbstractAttribute
+import io.github.ayushmaanbhav.productFarm.entity.Attribute
+import io.github.ayushmaanbhav.productFarm.entity.ProductFunctionality
+import io.github.ayushmaanbhav.productFarm.entity.Rule
+import io.github.ayushmaanbhav.productFarm.entity.compositeId.AbstractAttributeTagId
+import io.github.ayushmaanbhav.productFarm.entity.compositeId.FunctionalityRequiredAttributeId
+import io.github.ayushmaanbhav.productFarm.entity.compositeId.RuleInputAttributeId
+import io.github.ayushmaanbhav.productFarm.entity.compositeId.RuleOutputAttributeId
+import io.github.ayushmaanbhav.productFarm.entity.relationship.AbstractAttributeTag
+import io.github.ayushmaanbhav.productFarm.entity.relationship.FunctionalityRequiredAttribute
+import io.github.ayushmaanbhav.productFarm.entity.relationship.RuleInputAttribute
+import io.github.ayushmaanbhav.productFarm.entity.relationship.RuleOutputAttribute
+import io.github.ayushmaanbhav.productFarm.entity.repository.AbstractAttributeRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.AbstractAttributeTagRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.AttributeDisplayNameRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.AttributeRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductFunctionalityRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductRepo
+import io.github.ayushmaanbhav.productFarm.model.RuleDisplayExpression
+import io.github.ayushmaanbhav.productFarm.service.ProductServiceTest.Companion.product
+import io.github.ayushmaanbhav.productFarm.transformer.CreateAttributeTransformer
+import io.github.ayushmaanbhav.productFarm.transformer.GetAttributeByTagTransformer
+import io.github.ayushmaanbhav.productFarm.transformer.GetFunctionalityAttributeTransformer
+import io.github.ayushmaanbhav.productFarm.transformer.RuleTransformer
+import io.github.ayushmaanbhav.productFarm.util.EvaluationMetrics
+import io.github.ayushmaanbhav.productFarm.util.RuleUtil
+import io.github.ayushmaanbhav.productFarm.util.generatePath
+import io.github.ayushmaanbhav.ruleEngine.config.Config
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.core.spec.style.StringSpec
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.string.shouldStartWith
+import io.micrometer.core.instrument.simple.SimpleMeterRegistry
+import io.mockk.clearAllMocks
+import io.mockk.every
+import io.mockk.mockk
+import io.mockk.slot
+import io.mockk.verify
+import java.util.*
+import org.springframework.http.HttpStatus
+
+class AttributeServiceTest : StringSpec() {
+    private val productRepo = mockk<ProductRepo>()
+    private val abstractAttributeRepo = mockk<AbstractAttributeRepo>()
+    private val attributeRepo = mockk<AttributeRepo>()
+    private val attributeDisplayNameRepo = mockk<AttributeDisplayNameRepo>()
+    private val createAttributeTransformer = mockk<CreateAttributeTransformer>()
+    private val ruleTransformer = mockk<RuleTransformer>()
+    private val productFunctionalityRepo = mockk<ProductFunctionalityRepo>()
+    private val getFunctionalityAttributeTransformer = mockk<GetFunctionalityAttributeTransformer>()
+    private val abstractAttributeTagRepo = mockk<AbstractAttributeTagRepo>()
+    private val getAttributeByTagTransformer = mockk<GetAttributeByTagTransformer>()
+    private val attributeService = AttributeService(
+        createAttributeTransformer = createAttributeTransformer,
+        getAttributeTransformer = mockk(),
+        getFunctionalityAttributeTransformer = getFunctionalityAttributeTransformer,
+        getAttributeByTagTransformer = getAttributeByTagTransformer,
+        productRepo = productRepo,
+        abstractAttributeRepo = abstractAttributeRepo,
+        attributeRepo = attributeRepo,
+        attributeDisplayNameRepo = attributeDisplayNameRepo,
+        abstractAttributeTagRepo = abstractAttributeTagRepo,
+        productFunctionalityRepo = productFunctionalityRepo,
+        ruleTransformer = ruleTransformer,
+        ruleUtil = RuleUtil(Config.objectMapperBuilder().build(), JsonLogicEngine.Builder().build(), EvaluationMetrics(SimpleMeterRegistry())),
+        tenantAccessService = TenantAccessService(productRepo),
+    )
+
+    init {
+        beforeTest {
+            clearAllMocks()
+            RequestMetadata.setTenantId("tenantA")
+            every { productRepo.findById(PRODUCT) } returns Optional.of(product(PRODUCT, "tenantA"))
+            every { productRepo.existsById(PRODUCT) } returns true
+            every { abstractAttributeRepo.existsById(any()) } returns true
+            every { abstractAttributeRepo.getReferenceById(any()) } returns abstractAttribute(immutable = false)
+            every { createAttributeTransformer.forward(any()) } answers { attribute(firstArg<Pair<String, CreateAttributeRequest>>().second) }
+            every { ruleTransformer.forward(any<Rule>()) } answers { modelRule(firstArg()) }
+            every { attributeRepo.findAllById(any()) } returns listOf()
+            every { attributeRepo.findAllByProductIdOrderByPathAsc(PRODUCT) } returns listOf()
+            every { attributeRepo.save(any()) } answers { firstArg() }
+            every { attributeRepo.saveAll(any<List<Attribute>>()) } answers { firstArg() }
+        }
+        afterTest { RequestMetadata.resetTenantId() }
+
+        "get should reject an attribute of a product of another tenant" {
+            // Arrange
+            RequestMetadata.setTenantId("tenantA")
+            every { productRepo.findById("productB") } returns Optional.of(product("productB", "tenantB"))
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { attributeService.get("productB", "cover.sumAssured") }
+
+            // Assert
+            exception.code shouldBe HttpStatus.FORBIDDEN.value()
+            verify(exactly = 0) { attributeDisplayNameRepo.findById(any()) }
+        }
+
+        "bulkCreate should save nothing when one rule of the batch creates a cycle" {
+            // Arrange
+            val requests = listOf(
+                request("sumAssured", AttributeValueType.FIXED_VALUE),
+                request("premium", AttributeValueType.RULE_DRIVEN, inputs = listOf("sumAssured", "tax")),
+                request("tax", AttributeValueType.RULE_DRIVEN, inputs = listOf("premium")),
+            )
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { attributeService.bulkCreate(PRODUCT, requests) }
+
+            // Assert
+            exception.code shouldBe HttpStatus.BAD_REQUEST.value()
+            exception.errors!!.single().message!! shouldStartWith "Rules contain a cycle: "
+            verify(exactly = 0) { attributeRepo.saveAll(any<List<Attribute>>()) }
+        }
+
+        "bulkCreate should save the batch when its rules do not form a cycle" {
+            // Arrange
+            val requests = listOf(
+                request("sumAssured", AttributeValueType.FIXED_VALUE),
+                request("premium", AttributeValueType.RULE_DRIVEN, inputs = listOf("sumAssured")),
+                request("tax", AttributeValueType.RULE_DRIVEN, inputs = listOf("premium")),
+            )
+
+            // Act
+            attributeService.bulkCreate(PRODUCT, requests)
+
+            // Assert
+            verify { attributeRepo.saveAll(match<List<Attribute>> { it.map(Attribute::path) == listOf(path("sumAssured"), path("premium"), path("tax")) }) }
+        }
+
+        "bulkCreate should reject an attribute produced by more than one rule before looking for cycles" {
+            // Arrange
+            val requests = listOf(
+                request("x", AttributeValueType.RULE_DRIVEN, inputs = listOf("y")),
+                request("b", AttributeValueType.RULE_DRIVEN, outputs = listOf("b", "x")),
+                request("y", AttributeValueType.RULE_DRIVEN, inputs = listOf("x")),
+            )
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { attributeService.bulkCreate(PRODUCT, requests) }
+
+            // Assert
+            exception.errors!!.map { it.message } shouldBe listOf(
+                "Attribute ${path("x")} is output by more than one rule: ${path("x")}, ${path("b")}"
+            )
+            verify(exactly = 0) { attributeRepo.saveAll(any<List<Attribute>>()) }
+        }
+
+        "bulkCreate should reject a rule output which is a definition only attribute of the same batch" {
+            // Arrange
+            val requests = listOf(
+                request("premium", AttributeValueType.RULE_DRIVEN, outputs = listOf("premium", "tax")),
+                request("tax", AttributeValueType.JUST_DEFINITION),
+            )
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { attributeService.bulkCreate(PRODUCT, requests) }
+
+            // Assert
+            exception.errors!!.map { it.message } shouldBe listOf(
+                "Cover.base.premium: Rule output attribute is a definition only attribute: ${path("tax")}"
+            )
+            verify(exactly = 0) { attributeRepo.findAllById(listOf(path("tax"))) }
+            verify(exactly = 0) { attributeRepo.saveAll(any<List<Attribute>>()) }
+        }
+
+        "create should reject a rule output which is an immutable attribute" {
+            // Arrange
+            every { attributeRepo.findAllById(listOf(path("tax"))) } returns listOf(
+                attribute(request("tax", AttributeValueType.FIXED_VALUE), immutable = true)
+            )
+
+            // Act
+            val exception = shouldThrow<ValidatorException> {
+                attributeService.create(PRODUCT, request("premium", AttributeValueType.RULE_DRIVEN, outputs = listOf("premium", "tax")))
+            }
+
+            // Assert
+            exception.errors!!.map { it.message } shouldBe listOf("Rule output attribute is immutable: ${path("tax")}")
+            verify(exactly = 0) { attributeRepo.save(any()) }
+        }
+
+        "create should reject a rule output which is a definition only attribute" {
+            // Arrange
+            every { attributeRepo.findAllById(listOf(path("tax"))) } returns listOf(
+                attribute(request("tax", AttributeValueType.JUST_DEFINITION))
+            )
+
+            // Act
+            val exception = shouldThrow<ValidatorException> {
+                attributeService.create(PRODUCT, request("premium", AttributeValueType.RULE_DRIVEN, outputs = listOf("premium", "tax")))
+            }
+
+            // Assert
+            exception.errors!!.map { it.message } shouldBe listOf("Rule output attribute is a definition only attribute: ${path("tax")}")
+            verify(exactly = 0) { attributeRepo.save(any()) }
+        }
+
+        "create should reject a rule output which does not exist" {
+            // Act
+            val exception = shouldThrow<ValidatorException> {
+                attributeService.create(PRODUCT, request("premium", AttributeValueType.RULE_DRIVEN, outputs = listOf("premium", "tax")))
+            }
+
+            // Assert
+            exception.errors!!.map { it.message } shouldBe listOf("Rule output attribute does not exist: ${path("tax")}")
+            verify(exactly = 0) { attributeRepo.save(any()) }
+        }
+
+        "getFunctionalityAttribute should return the attributes in the order of the required attributes" {
+            // Arrange
+            val premiumAbstractPath = generatePath(PRODUCT, "Cover", null, "premium")
+            val sumAssuredAbstractPath = generatePath(PRODUCT, "Cover", null, "sumAssured")
+            val extraPremiumPath = generatePath(PRODUCT, "Cover", "extra", "premium")
+            every { productFunctionalityRepo.findByProductIdAndName(PRODUCT, "quote") } returns Optional.of(
+                functionality(premiumAbstractPath, sumAssuredAbstractPath)
+            )
+            every {
+                attributeRepo.findAllByAbstractAttribute_AbstractPathIn(setOf(premiumAbstractPath, sumAssuredAbstractPath))
+            } returns listOf(
+                attribute(path("sumAssured"), sumAssuredAbstractPath),
+                attribute(extraPremiumPath, premiumAbstractPath),
+                attribute(path("premium"), premiumAbstractPath),
+            )
+            val attributes = slot<List<Attribute>>()
+            every { getFunctionalityAttributeTransformer.forward(capture(attributes)) } returns mockk()
+
+            // Act
+            attributeService.getFunctionalityAttribute(PRODUCT, "quote")
+
+            // Assert
+            attributes.captured.map { it.path } shouldBe listOf(extraPremiumPath, path("premium"), path("sumAssured"))
+        }
+
+        "getAttributeByTag should return the attributes of every tag in a namespace once" {
+            // Arrange
+            val premiumAbstractPath = generatePath(PRODUCT, "Cover", null, "premium")
+            val taxAbstractPath = generatePath(PRODUCT, "Cover", null, "tax")
+            every { abstractAttributeTagRepo.getByProductIdAndTagStartingWith(PRODUCT, "pricing:") } returns listOf(
+                tag(premiumAbstractPath, "pricing:base"),
+                tag(taxAbstractPath, "pricing:loading"),
+                tag(premiumAbstractPath, "pricing:loading"),
+            )
+            every {
+                attributeRepo.findAllByAbstractAttribute_AbstractPathIn(setOf(premiumAbstractPath, taxAbstractPath))
+            } returns listOf(attribute(path("tax"), taxAbstractPath), attribute(path("premium"), premiumAbstractPath))
+            val attributes = mutableListOf<Attribute>()
+            every { getAttributeByTagTransformer.forward(capture(attributes)) } returns mockk()
+
+            // Act
+            attributeService.getAttributeByTag(PRODUCT, "pricing:")
+
+            // Assert
+            attributes.map { it.path } shouldBe listOf(path("premium"), path("tax"))
+            verify(exactly = 0) { abstractAttributeTagRepo.getByProductIdAndTag(any(), any()) }
+        }
+
+        "getAttributeByTag should match a namespaced tag exactly" {
+            // Arrange
+            every { abstractAttributeTagRepo.getByProductIdAndTag(PRODUCT, "pricing:base") } returns listOf()
+
+            // Act
+            attributeService.getAttributeByTag(PRODUCT, "pricing:base")
+
+            // Assert
+            verify { abstractAttributeTagRepo.getByProductIdAndTag(PRODUCT, "pricing:base") }
+            verify(exactly = 0) { abstractAttributeTagRepo.getByProductIdAndTagStartingWith(any(), any()) }
+        }
+    }
+
+    private fun path(name: String) = generatePath(PRODUCT, "Cover", "base", name)
+
+    private fun request(
+        name: String, type: AttributeValueType, inputs: List<String> = listOf(), outputs: List<String> = listOf(name),
+    ) = CreateAttributeRequest(
+        displayName = "Cover.base.$name",
+        value = IntNode(10).takeIf { type == AttributeValueType.FIXED_VALUE },
+        rule = CreateRuleRequest(
+            type = "calculation",
+            inputAttributes = inputs.map(::path).toCollection(LinkedHashSet()),
+            outputAttributes = outputs.map(::path).toCollection(LinkedHashSet()),
+            displayExpression = RuleDisplayExpression(null, """{"$name": 10}""", null),
+            description = null,
+        ).takeIf { type == AttributeValueType.RULE_DRIVEN },
+        type = type,
+    )
+
+    private fun attribute(request: CreateAttributeRequest, immutable: Boolean = false): Attribute {
+        val path = path(request.displayName.substringAfterLast("."))
+        return Attribute(
+            path = path,
+            displayNames = listOf(),
+            abstractAttribute = abstractAttribute(immutable),
+            type = request.type,
+            value = request.value,
+            rule = request.rule?.let { rule ->
+                Rule(
+                    id = path,
+                    type = rule.type,
+                    inputAttributes = rule.inputAttributes.mapIndexed { index, it -> RuleInputAttribute(RuleInputAttributeId(path, it), index) },
+                    outputAttributes = rule.outputAttributes.mapIndexed { index, it -> RuleOutputAttribute(RuleOutputAttributeId(path, it), index) },
+                    displayExpression = "{}",
+                    displayExpressionVersion = "0.1",
+                    compiledExpression = rule.displayExpression.expression!!,
+                    description = null,
+                )
+            },
+            productId = PRODUCT,
+        )
+    }
+
+    private fun attribute(path: String, abstractPath: String) = Attribute(
+        path = path,
+        displayNames = listOf(),
+        abstractAttribute = mockk { every { this@mockk.abstractPath } returns abstractPath },
+        type = AttributeValueType.FIXED_VALUE,
+        value = IntNode(10),
+        rule = null,
+        productId = PRODUCT,
+    )
+
+    private fun tag(abstractPath: String, tag: String) = AbstractAttributeTag(AbstractAttributeTagId(abstractPath, tag), PRODUCT, 0)
+
+    private fun abstractAttribute(immutable: Boolean) = mockk<AbstractAttribute> { every { this@mockk.immutable } returns immutable }
+
+    private fun functionality(vararg requiredAbstractPaths: String) = ProductFunctionality(
+        id = "functionality",
+        name = "quote",
+        productId = PRODUCT,
+        immutable = false,
+        description = "quote",
+        requiredAttributes = requiredAbstractPaths.mapIndexed { index, it ->
+            FunctionalityRequiredAttribute(FunctionalityRequiredAttributeId("functionality", it), "required", index)
+        },
+        status = ProductFunctionalityStatus.ACTIVE,
+    )
+
+    private fun modelRule(rule: Rule) = io.github.ayushmaanbhav.productFarm.model.Rule(
+        type = rule.type,
+        inputAttributes = rule.inputAttributes.map { it.id.path }.toCollection(LinkedHashSet()),
+        outputAttributes = rule.outputAttributes.map { it.id.path }.toCollection(LinkedHashSet()),
+        displayExpression = RuleDisplayExpression(null, rule.compiledExpression, null),
+        displayExpressionVersion = rule.displayExpressionVersion,
+        compiledExpression = rule.compiledExpression,
+        description = rule.description,
+    )
+
+    companion object {
+        private const val PRODUCT = "product"
+    }
+}
@@ -0,0 +1,122 @@
+package io.github.ayushmaanbhav.productFarm.service
+
+import io.github.ayushmaanbhav.common.exception.ValidatorException
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.productFarm.api.evaluation.dto.AdhocRuleDto
+import io.github.ayushmaanbhav.productFarm.api.evaluation.dto.EvaluateAdhocRequest
+import io.github.ayushmaanbhav.productFarm.config.RuleFrameworkConfig
+import io.github.ayushmaanbhav.productFarm.util.EvaluationMetrics
+import io.github.ayushmaanbhav.productFarm.util.RuleUtil
+import io.github.ayushmaanbhav.ruleEngine.JsonLogicEvaluator
+import io.github.ayushmaanbhav.ruleEngine.api.EvaluationEngine
+import io.github.ayushmaanbhav.ruleEngine.exception.RuleEngineException
+import io.github.ayushmaanbhav.ruleEngine.exception.RuleExecutorUnavailableException
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.core.spec.style.StringSpec
+import io.kotest.matchers.shouldBe
+import io.micrometer.core.instrument.simple.SimpleMeterRegistry
+import io.mockk.every
+import io.mockk.mockk
+
+class EvaluationServiceTest : StringSpec() {
+    private val config = RuleFrameworkConfig.RuleFrameworkConfig()
+    private val jsonLogicEngine = JsonLogicEngine.Builder().build()
+    private val meterRegistry = SimpleMeterRegistry()
+    private val evaluationService = EvaluationService(
+        ruleUtil = RuleUtil(config.objectMapper, jsonLogicEngine, EvaluationMetrics(meterRegistry)),
+        evaluationEngine = JsonLogicEvaluator(config, jsonLogicEngine, meterRegistry),
+        evaluationMetrics = EvaluationMetrics(meterRegistry),
+    )
+    private val evaluationEngine = mockk<EvaluationEngine>()
+    private val mockEngineEvaluationService = EvaluationService(
+        ruleUtil = RuleUtil(config.objectMapper, jsonLogicEngine, EvaluationMetrics(meterRegistry)),
+        evaluationEngine = evaluationEngine,
+        evaluationMetrics = EvaluationMetrics(meterRegistry),
+    )
+
+    private val premiumRule = AdhocRuleDto(
+        id = "premium",
+        type = "calculation",
+        inputAttributes = linkedSetOf("sumAssured"),
+        outputAttributes = linkedSetOf("premium"),
+        expression = """{"premium": {"*": [{"var": "sumAssured"}, 2]}}""",
+    )
+    private val totalRule = AdhocRuleDto(
+        id = "total",
+        type = "calculation",
+        inputAttributes = linkedSetOf("premium"),
+        outputAttributes = linkedSetOf("total"),
+        expression = """{"total": {"+": [{"var": "premium"}, 10]}}""",
+    )
+
+    init {
+        beforeTest { meterRegistry.clear() }
+
+        "evaluateAdhoc should run interdependent rules in dependency order" {
+            // Arrange
+            val request = EvaluateAdhocRequest(rules = listOf(totalRule, premiumRule), input = linkedMapOf("sumAssured" to 100))
+
+            // Act
+            val response = evaluationService.evaluateAdhoc(request)
+
+            // Assert
+            response.executionPlan shouldBe listOf("premium", "total")
+            response.outputs.keys.toList() shouldBe listOf("premium", "total")
+            response.outputs.mapValues { it.value.toString().toBigDecimal().toInt() } shouldBe mapOf("premium" to 200, "total" to 210)
+            response.provenance shouldBe mapOf("premium" to "premium", "total" to "total")
+            response.skippedRules shouldBe listOf()
+        }
+
+        "evaluateAdhoc should reject an attribute output by more than one rule" {
+            // Arrange
+            val request = EvaluateAdhocRequest(
+                rules = listOf(premiumRule, premiumRule.copy(id = "premium2")), input = linkedMapOf("sumAssured" to 100)
+            )
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { evaluationService.evaluateAdhoc(request) }
+
+            // Assert
+            exception.code shouldBe 400
+            exception.errors!!.map { it.message } shouldBe listOf("Attribute premium is output by more than one rule: premium, premium2")
+        }
+
+        "evaluateAdhoc should report an unavailable rule executor as service unavailable" {
+            // Arrange
+            every { evaluationEngine.evaluate(any(), any()) } throws RuleExecutorUnavailableException("unavailable")
+            val request = EvaluateAdhocRequest(rules = listOf(premiumRule), input = linkedMapOf("sumAssured" to 100))
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { mockEngineEvaluationService.evaluateAdhoc(request) }
+
+            // Assert
+            exception.code shouldBe 503
+        }
+
+        "evaluateAdhoc should report a failing rule as a bad request" {
+            // Arrange
+            every { evaluationEngine.evaluate(any(), any()) } throws RuleEngineException("Got failure on running rule: premium")
+            val request = EvaluateAdhocRequest(rules = listOf(premiumRule), input = linkedMapOf("sumAssured" to 100))
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { mockEngineEvaluationService.evaluateAdhoc(request) }
+
+            // Assert
+            exception.code shouldBe 400
+            exception.errors!!.map { it.message } shouldBe listOf("Got failure on running rule: premium")
+        }
+
+        "evaluateAdhoc should record the evaluation and the execution of each rule" {
+            // Arrange
+            val request = EvaluateAdhocRequest(rules = listOf(totalRule, premiumRule), input = linkedMapOf("sumAssured" to 100))
+
+            // Act
+            evaluationService.evaluateAdhoc(request)
+
+            // Assert
+            meterRegistry.get(EvaluationMetrics.EVALUATION_METRIC).tag(EvaluationMetrics.TYPE_TAG, EvaluationMetrics.ADHOC_EVALUATION)
+                .timer().count() shouldBe 1
+            meterRegistry.get(JsonLogicEvaluator.RULE_EXECUTION_METRIC).tag("type", "calculation").timer().count() shouldBe 2
+        }
+    }
+}
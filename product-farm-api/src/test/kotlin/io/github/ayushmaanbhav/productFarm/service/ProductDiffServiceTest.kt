@@ -0,0 +1,116 @@
+package io.github.ayushmaanbhav.productFarm.service
+
+import com.fasterxml.jackson.databind.node.IntNode
+import io.github.ayushmaanbhav.common.model.RequestMetadata
+import io.github.ayushmaanbhav.productFarm.api.product.dto.FieldChange
+import io.github.ayushmaanbhav.productFarm.constant.AttributeValueType
+import io.github.ayushmaanbhav.productFarm.constant.Constant
+import io.github.ayushmaanbhav.productFarm.entity.Attribute
+import io.github.ayushmaanbhav.productFarm.entity.Rule
+import io.github.ayushmaanbhav.productFarm.entity.repository.AbstractAttributeRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.AttributeRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductFunctionalityRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductRepo
+import io.github.ayushmaanbhav.productFarm.service.ProductServiceTest.Companion.product
+import io.github.ayushmaanbhav.productFarm.util.generatePath
+import io.kotest.core.spec.style.StringSpec
+import io.kotest.matchers.shouldBe
+import io.mockk.every
+import io.mockk.mockk
+import java.util.*
+
+class ProductDiffServiceTest : StringSpec() {
+    private val productRepo = mockk<ProductRepo>()
+    private val abstractAttributeRepo = mockk<AbstractAttributeRepo>()
+    private val attributeRepo = mockk<AttributeRepo>()
+    private val productFunctionalityRepo = mockk<ProductFunctionalityRepo>()
+    private val productDiffService = ProductDiffService(
+        productRepo = productRepo,
+        abstractAttributeRepo = abstractAttributeRepo,
+        attributeRepo = attributeRepo,
+        productFunctionalityRepo = productFunctionalityRepo,
+        tenantAccessService = TenantAccessService(productRepo),
+    )
+
+    init {
+        beforeTest {
+            RequestMetadata.setTenantId("tenantA")
+            listOf(PARENT, CHILD).forEach {
+                every { productRepo.existsById(it) } returns true
+                every { productRepo.findById(it) } returns Optional.of(product(it, "tenantA"))
+                every { abstractAttributeRepo.findAllByProductId(it) } returns listOf()
+                every { productFunctionalityRepo.findAllByProductId(it) } returns listOf()
+            }
+        }
+        afterTest { RequestMetadata.resetTenantId() }
+
+        "diff should report the one rule changed in a clone" {
+            // Arrange
+            every { attributeRepo.findAllByProductIdOrderByPathAsc(PARENT) } returns listOf(
+                ruleAttribute(PARENT, "premium", """{"*":[{"var":"sumAssured"},0.01]}"""),
+                ruleAttribute(PARENT, "tax", """{"*":[{"var":"premium"},0.18]}"""),
+                valueAttribute(PARENT, "sumAssured"),
+            )
+            every { attributeRepo.findAllByProductIdOrderByPathAsc(CHILD) } returns listOf(
+                ruleAttribute(CHILD, "premium", """{"*":[{"var":"sumAssured"},0.02]}"""),
+                ruleAttribute(CHILD, "tax", """{"*":[{"var":"premium"},0.18]}"""),
+                valueAttribute(CHILD, "sumAssured"),
+            )
+
+            // Act
+            val diff = productDiffService.diff(PARENT, CHILD).get()
+
+            // Assert
+            diff.attributes.added shouldBe listOf()
+            diff.attributes.removed shouldBe listOf()
+            diff.attributes.modified shouldBe mapOf(
+                relativePath("premium") to listOf(
+                    FieldChange("rule.expression", """{"*":[{"var":"sumAssured"},0.01]}""", """{"*":[{"var":"sumAssured"},0.02]}""")
+                )
+            )
+        }
+
+        "diff should report the fields which are present only on the right" {
+            // Arrange
+            every { attributeRepo.findAllByProductIdOrderByPathAsc(PARENT) } returns listOf(valueAttribute(PARENT, "premium"))
+            every { attributeRepo.findAllByProductIdOrderByPathAsc(CHILD) } returns listOf(
+                ruleAttribute(CHILD, "premium", """{"*":[{"var":"sumAssured"},0.01]}""")
+            )
+
+            // Act
+            val diff = productDiffService.diff(PARENT, CHILD).get()
+
+            // Assert
+            diff.attributes.modified.getValue(relativePath("premium")).map { it.field } shouldBe listOf(
+                "type", "value", "rule.type", "rule.inputAttributes", "rule.outputAttributes", "rule.expression", "rule.description",
+            )
+        }
+    }
+
+    private fun relativePath(name: String) = generatePath("", "cover", null, name).removePrefix(Constant.COMPONENT_SEPARATOR)
+
+    private fun valueAttribute(productId: String, name: String) = Attribute(
+        path = generatePath(productId, "cover", null, name),
+        displayNames = listOf(),
+        abstractAttribute = mockk(),
+        type = AttributeValueType.FIXED_VALUE,
+        value = IntNode(10),
+        rule = null,
+        productId = productId,
+    )
+
+    private fun ruleAttribute(productId: String, name: String, expression: String) = Attribute(
+        path = generatePath(productId, "cover", null, name),
+        displayNames = listOf(),
+        abstractAttribute = mockk(),
+        type = AttributeValueType.RULE_DRIVEN,
+        value = null,
+        rule = Rule("$productId-$name", "calculation", listOf(), listOf(), expression, "1", expression, null),
+        productId = productId,
+    )
+
+    companion object {
+        private const val PARENT = "parent"
+        private const val CHILD = "child"
+    }
+}
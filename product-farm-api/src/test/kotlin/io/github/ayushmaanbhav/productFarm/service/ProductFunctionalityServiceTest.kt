@@ -0,0 +1,227 @@
+package io.github.ayushmaanbhav.productFarm.service
+
+import io.github.ayushmaanbhav.common.exception.ValidatorException
+import io.github.ayushmaanbhav.common.model.RequestMetadata
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.productFarm.constant.AttributeValueType
+import io.github.ayushmaanbhav.productFarm.constant.ProductFunctionalityStatus
+import io.github.ayushmaanbhav.productFarm.entity.AbstractAttribute
+import io.github.ayushmaanbhav.productFarm.entity.Attribute
+import io.github.ayushmaanbhav.productFarm.entity.ProductFunctionality
+import io.github.ayushmaanbhav.productFarm.entity.Rule
+import io.github.ayushmaanbhav.productFarm.entity.compositeId.FunctionalityRequiredAttributeId
+import io.github.ayushmaanbhav.productFarm.entity.relationship.FunctionalityRequiredAttribute
+import io.github.ayushmaanbhav.productFarm.entity.repository.AbstractAttributeRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.AttributeRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductFunctionalityRepo
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductRepo
+import io.github.ayushmaanbhav.productFarm.model.RuleDisplayExpression
+import io.github.ayushmaanbhav.productFarm.service.ProductServiceTest.Companion.product
+import io.github.ayushmaanbhav.productFarm.transformer.RuleTransformer
+import io.github.ayushmaanbhav.productFarm.util.EvaluationMetrics
+import io.github.ayushmaanbhav.productFarm.util.RuleUtil
+import io.github.ayushmaanbhav.productFarm.util.generatePath
+import io.github.ayushmaanbhav.ruleEngine.config.Config
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.core.spec.style.StringSpec
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.string.shouldStartWith
+import io.micrometer.core.instrument.simple.SimpleMeterRegistry
+import io.mockk.clearAllMocks
+import io.mockk.every
+import io.mockk.mockk
+import io.mockk.verify
+import java.util.*
+
+class ProductFunctionalityServiceTest : StringSpec() {
+    private val productRepo = mockk<ProductRepo>()
+    private val abstractAttributeRepo = mockk<AbstractAttributeRepo>()
+    private val attributeRepo = mockk<AttributeRepo>()
+    private val productFunctionalityRepo = mockk<ProductFunctionalityRepo>()
+    private val ruleTransformer = mockk<RuleTransformer>()
+    private val productFunctionalityService = ProductFunctionalityService(
+        abstractAttributeRepo = abstractAttributeRepo,
+        attributeRepo = attributeRepo,
+        productFunctionalityRepo = productFunctionalityRepo,
+        createProductFunctionalityTransformer = mockk(),
+        getProductFunctionalityTransformer = mockk(),
+        getProductFunctionalityStatusTransformer = mockk(),
+        ruleTransformer = ruleTransformer,
+        ruleUtil = RuleUtil(Config.objectMapperBuilder().build(), JsonLogicEngine.Builder().build(), EvaluationMetrics(SimpleMeterRegistry())),
+        tenantAccessService = TenantAccessService(productRepo),
+        attributeService = AttributeService(
+            createAttributeTransformer = mockk(),
+            getAttributeTransformer = mockk(),
+            getFunctionalityAttributeTransformer = mockk(),
+            getAttributeByTagTransformer = mockk(),
+            productRepo = productRepo,
+            abstractAttributeRepo = abstractAttributeRepo,
+            attributeRepo = attributeRepo,
+            attributeDisplayNameRepo = mockk(),
+            abstractAttributeTagRepo = mockk(),
+            productFunctionalityRepo = productFunctionalityRepo,
+            ruleTransformer = ruleTransformer,
+            ruleUtil = RuleUtil(Config.objectMapperBuilder().build(), JsonLogicEngine.Builder().build(), EvaluationMetrics(SimpleMeterRegistry())),
+            tenantAccessService = TenantAccessService(productRepo),
+        ),
+    )
+
+    private val premiumAbstractPath = generatePath(PRODUCT, "cover", null, "premium")
+    private val premiumPath = generatePath(PRODUCT, "cover", "base", "premium")
+    private val discountAbstractPath = generatePath(PRODUCT, "cover", null, "discount")
+    private val discountPath = generatePath(PRODUCT, "cover", "base", "discount")
+    private val functionality = ProductFunctionality(
+        id = "functionality",
+        name = "quote",
+        productId = PRODUCT,
+        immutable = false,
+        description = "quote",
+        requiredAttributes = listOf(
+            FunctionalityRequiredAttribute(FunctionalityRequiredAttributeId("functionality", premiumAbstractPath), "premium", 0)
+        ),
+        status = ProductFunctionalityStatus.PENDING_APPROVAL,
+    )
+
+    init {
+        beforeTest {
+            clearAllMocks()
+            functionality.status = ProductFunctionalityStatus.PENDING_APPROVAL
+            RequestMetadata.setTenantId("tenantA")
+            every { productRepo.findById(PRODUCT) } returns Optional.of(product(PRODUCT, "tenantA"))
+            every { productFunctionalityRepo.findByProductIdAndName(PRODUCT, "quote") } returns Optional.of(functionality)
+            every { abstractAttributeRepo.findAllById(listOf(premiumAbstractPath)) } returns listOf(
+                mockk<AbstractAttribute> { every { abstractPath } returns premiumAbstractPath }
+            )
+        }
+        afterTest { RequestMetadata.resetTenantId() }
+
+        "precheck should pass a functionality whose attributes are all produced" {
+            // Arrange
+            givenAttributes(premiumAttribute(premiumRule()))
+
+            // Act
+            val response = productFunctionalityService.precheck(PRODUCT, "quote").get()
+
+            // Assert
+            response.errors shouldBe listOf()
+            response.ready shouldBe true
+        }
+
+        "precheck should fail a functionality with a rule driven attribute missing its producing rule" {
+            // Arrange
+            givenAttributes(premiumAttribute(null))
+
+            // Act
+            val response = productFunctionalityService.precheck(PRODUCT, "quote").get()
+
+            // Assert
+            response.errors shouldBe listOf("No rule found for rule driven attribute $premiumPath")
+            response.ready shouldBe false
+        }
+
+        "precheck should fail a rule whose stored expression is not what its display expression compiles to" {
+            // Arrange
+            givenAttributes(premiumAttribute(premiumRule(compiledExpression = """{"premium": 200}""")))
+
+            // Act
+            val response = productFunctionalityService.precheck(PRODUCT, "quote").get()
+
+            // Assert
+            response.ready shouldBe false
+        }
+
+        "precheck should fail a product with two rules producing the same attribute" {
+            // Arrange
+            givenAttributes(
+                premiumAttribute(premiumRule()),
+                premiumAttribute(
+                    premiumRule(compiledExpression = """{"premium":200}""", id = "rule2", displayExpression = """{"premium": 200}""")
+                ).copy(path = "$premiumPath-copy"),
+            )
+
+            // Act
+            val response = productFunctionalityService.precheck(PRODUCT, "quote").get()
+
+            // Assert
+            response.ready shouldBe false
+            response.errors.single() shouldStartWith "Attribute $premiumPath has multiple producers: "
+        }
+
+        "precheck should fail a rule outputting an attribute which becomes immutable on approval" {
+            // Arrange
+            every { productFunctionalityRepo.findByProductIdAndName(PRODUCT, "quote") } returns
+                Optional.of(functionality.copy(immutable = true))
+            val discountRule = premiumRule(
+                compiledExpression = """{"discount":10}""",
+                id = "rule2",
+                displayExpression = """{"discount": 10}""",
+                outputs = linkedSetOf(discountPath, premiumPath),
+            )
+            givenAttributes(
+                premiumAttribute(null).copy(type = AttributeValueType.FIXED_VALUE),
+                premiumAttribute(discountRule).copy(
+                    path = discountPath,
+                    abstractAttribute = mockk { every { abstractPath } returns discountAbstractPath; every { immutable } returns false },
+                ),
+            )
+
+            // Act
+            val response = productFunctionalityService.precheck(PRODUCT, "quote").get()
+
+            // Assert
+            response.errors shouldBe listOf("Rule rule2 for attribute $discountPath: Rule output attribute is immutable: $premiumPath")
+            response.ready shouldBe false
+        }
+
+        "approve should reject a functionality failing the precheck" {
+            // Arrange
+            givenAttributes(premiumAttribute(null))
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { productFunctionalityService.approve(PRODUCT, "quote") }
+
+            // Assert
+            exception.errors!!.map { it.message } shouldBe listOf("No rule found for rule driven attribute $premiumPath")
+            functionality.status shouldBe ProductFunctionalityStatus.PENDING_APPROVAL
+            verify(exactly = 0) { productFunctionalityRepo.save(any()) }
+        }
+    }
+
+    private fun givenAttributes(vararg attributes: Attribute) {
+        every { attributeRepo.findAllByAbstractAttribute_AbstractPathIn(setOf(premiumAbstractPath)) } returns attributes.toList()
+        every { attributeRepo.findAllByProductIdOrderByPathAsc(PRODUCT) } returns attributes.toList()
+    }
+
+    private fun premiumRule(
+        compiledExpression: String = """{"premium":100}""",
+        id: String = "rule",
+        displayExpression: String = """{"premium": 100}""",
+        outputs: LinkedHashSet<String> = linkedSetOf(premiumPath),
+    ): Rule {
+        val rule = Rule(id, "calculation", listOf(), listOf(), "{}", "0.1", compiledExpression, null)
+        every { ruleTransformer.forward(rule) } returns io.github.ayushmaanbhav.productFarm.model.Rule(
+            type = "calculation",
+            inputAttributes = linkedSetOf(),
+            outputAttributes = outputs,
+            displayExpression = RuleDisplayExpression(null, displayExpression, null),
+            displayExpressionVersion = "0.1",
+            compiledExpression = compiledExpression,
+            description = null,
+        )
+        return rule
+    }
+
+    private fun premiumAttribute(rule: Rule?) = Attribute(
+        path = premiumPath,
+        displayNames = listOf(),
+        abstractAttribute = mockk { every { abstractPath } returns premiumAbstractPath; every { immutable } returns false },
+        type = AttributeValueType.RULE_DRIVEN,
+        value = null,
+        rule = rule,
+        productId = PRODUCT,
+    )
+
+    companion object {
+        private const val PRODUCT = "product"
+    }
+}
@@ -0,0 +1,119 @@
+package io.github.ayushmaanbhav.productFarm.service
+
+import io.github.ayushmaanbhav.common.exception.ValidatorException
+import io.github.ayushmaanbhav.common.model.RequestMetadata
+import io.github.ayushmaanbhav.productFarm.api.product.dto.CreateProductRequest
+import io.github.ayushmaanbhav.productFarm.api.product.dto.GetProductResponse
+import io.github.ayushmaanbhav.productFarm.constant.ProductStatus
+import io.github.ayushmaanbhav.productFarm.constant.ProductTemplateType
+import io.github.ayushmaanbhav.productFarm.entity.Product
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductRepo
+import io.github.ayushmaanbhav.productFarm.transformer.CreateProductTransformer
+import io.github.ayushmaanbhav.productFarm.transformer.GetProductTransformer
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.core.spec.style.StringSpec
+import io.kotest.matchers.shouldBe
+import io.mockk.every
+import io.mockk.mockk
+import io.mockk.verify
+import java.time.LocalDateTime
+import java.util.*
+import org.springframework.http.HttpStatus
+
+class ProductServiceTest : StringSpec() {
+    private val productRepo = mockk<ProductRepo>()
+    private val getProductTransformer = mockk<GetProductTransformer>()
+    private val createProductTransformer = mockk<CreateProductTransformer>()
+    private val productService = ProductService(
+        createProductTransformer = createProductTransformer,
+        getProductTransformer = getProductTransformer,
+        productRepo = productRepo,
+        productApprovalService = mockk(),
+        tenantAccessService = TenantAccessService(productRepo),
+    )
+
+    init {
+        afterTest { RequestMetadata.resetTenantId() }
+
+        "getProduct should reject a product of another tenant" {
+            // Arrange
+            RequestMetadata.setTenantId("tenantA")
+            every { productRepo.findById("productB") } returns Optional.of(product("productB", "tenantB"))
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { productService.getProduct("productB") }
+
+            // Assert
+            exception.code shouldBe HttpStatus.FORBIDDEN.value()
+            verify(exactly = 0) { getProductTransformer.forward(any()) }
+        }
+
+        "getProduct should return a product of the same tenant" {
+            // Arrange
+            RequestMetadata.setTenantId("tenantA")
+            val product = product("productA", "tenantA")
+            val response = mockk<GetProductResponse>()
+            every { productRepo.findById("productA") } returns Optional.of(product)
+            every { getProductTransformer.forward(product) } returns response
+
+            // Act
+            val result = productService.getProduct("productA")
+
+            // Assert
+            result shouldBe Optional.of(response)
+        }
+
+        "getProduct should reject a request without a tenant" {
+            // Arrange
+            every { productRepo.findById("productA") } returns Optional.of(product("productA", "tenantA"))
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { productService.getProduct("productA") }
+
+            // Assert
+            exception.code shouldBe HttpStatus.BAD_REQUEST.value()
+            verify(exactly = 0) { getProductTransformer.forward(any()) }
+        }
+
+        "getProduct should reject a product not assigned to any tenant" {
+            // Arrange
+            RequestMetadata.setTenantId("tenantA")
+            every { productRepo.findById("legacy") } returns Optional.of(product("legacy", null))
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { productService.getProduct("legacy") }
+
+            // Assert
+            exception.code shouldBe HttpStatus.FORBIDDEN.value()
+            verify(exactly = 0) { getProductTransformer.forward(any()) }
+        }
+
+        "create should reject the id of another tenant's product without overwriting it" {
+            // Arrange
+            RequestMetadata.setTenantId("tenantA")
+            every { productRepo.existsById("productB") } returns true
+            val request = mockk<CreateProductRequest> { every { id } returns "productB" }
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { productService.create(request) }
+
+            // Assert
+            exception.code shouldBe HttpStatus.BAD_REQUEST.value()
+            verify(exactly = 0) { productRepo.save(any()) }
+        }
+    }
+
+    companion object {
+        fun product(id: String, tenantId: String?) = Product(
+            id = id,
+            name = id,
+            status = ProductStatus.DRAFT,
+            effectiveFrom = LocalDateTime.now(),
+            expiryAt = LocalDateTime.now().plusYears(1),
+            templateType = ProductTemplateType.INSURANCE,
+            parentProductId = null,
+            description = null,
+            tenantId = tenantId,
+        )
+    }
+}
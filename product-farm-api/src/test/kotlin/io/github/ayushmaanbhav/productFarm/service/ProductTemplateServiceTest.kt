@@ -0,0 +1,94 @@
+package io.github.ayushmaanbhav.productFarm.service
+
+import io.github.ayushmaanbhav.common.exception.ValidatorException
+import io.github.ayushmaanbhav.productFarm.api.productTemplate.dto.ProductTemplateEnumerationDto
+import io.github.ayushmaanbhav.productFarm.constant.ProductTemplateType
+import io.github.ayushmaanbhav.productFarm.entity.ProductTemplateEnumeration
+import io.github.ayushmaanbhav.productFarm.entity.repository.ProductTemplateEnumerationRepo
+import io.github.ayushmaanbhav.productFarm.transformer.ProductTemplateEnumerationTransformer
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.core.spec.style.StringSpec
+import io.kotest.matchers.shouldBe
+import io.mockk.every
+import io.mockk.mockk
+import io.mockk.slot
+import org.springframework.data.domain.Pageable
+import org.springframework.http.HttpStatus
+
+class ProductTemplateServiceTest : StringSpec() {
+    private val enumerationTransformer = ProductTemplateEnumerationTransformer()
+    private val enumerationRepo = mockk<ProductTemplateEnumerationRepo>()
+    private val productTemplateService = ProductTemplateService(enumerationTransformer, enumerationRepo)
+    private val enumerations = mutableListOf<ProductTemplateEnumeration>()
+
+    init {
+        beforeTest {
+            enumerations.clear()
+            every { enumerationRepo.findAllByProductTemplateTypeOrderByNameAsc(any(), any()) } answers {
+                page(firstArg(), null, lastArg())
+            }
+            every { enumerationRepo.findAllByProductTemplateTypeAndNameGreaterThanOrderByNameAsc(any(), any(), any()) } answers {
+                page(firstArg(), secondArg(), lastArg())
+            }
+        }
+
+        "listEnumerations should neither repeat nor skip enumerations when one is created between pages" {
+            // Arrange
+            listOf("a", "b", "c", "d").forEach(::addEnumeration)
+
+            // Act
+            val firstPage = productTemplateService.listEnumerations(ProductTemplateType.INSURANCE, null, 2)
+            addEnumeration("bb")
+            val secondPage = productTemplateService.listEnumerations(ProductTemplateType.INSURANCE, firstPage.nextPageToken, 2)
+            val thirdPage = productTemplateService.listEnumerations(ProductTemplateType.INSURANCE, secondPage.nextPageToken, 2)
+
+            // Assert
+            listOf(firstPage, secondPage, thirdPage).flatMap { page -> page.enumerations.map { it.name } } shouldBe
+                listOf("a", "b", "bb", "c", "d")
+            thirdPage.nextPageToken shouldBe null
+        }
+
+        "listEnumerations should order enumerations by name and keep values in the order they were created with" {
+            // Arrange
+            val saved = slot<ProductTemplateEnumeration>()
+            every { enumerationRepo.existsByProductTemplateTypeAndName(any(), any()) } returns false
+            every { enumerationRepo.save(capture(saved)) } answers { saved.captured.also(enumerations::add) }
+
+            // Act
+            productTemplateService.createEnumeration(
+                ProductTemplateType.INSURANCE, ProductTemplateEnumerationDto("frequency", linkedSetOf("yearly", "monthly", "quarterly"), null)
+            )
+            addEnumeration("channel", linkedSetOf("online", "agent"))
+            val response = productTemplateService.listEnumerations(ProductTemplateType.INSURANCE, null, 10)
+
+            // Assert
+            response.enumerations.map { it.name } shouldBe listOf("channel", "frequency")
+            response.enumerations.map { it.values.toList() } shouldBe listOf(
+                listOf("online", "agent"),
+                listOf("yearly", "monthly", "quarterly"),
+            )
+        }
+
+        "listEnumerations should reject a page token which can not be decoded" {
+            // Act
+            val exception = shouldThrow<ValidatorException> {
+                productTemplateService.listEnumerations(ProductTemplateType.INSURANCE, "not a token!", 2)
+            }
+
+            // Assert
+            exception.code shouldBe HttpStatus.BAD_REQUEST.value()
+            exception.errors!!.map { it.message } shouldBe listOf("Invalid page token")
+        }
+    }
+
+    private fun addEnumeration(name: String, values: LinkedHashSet<String> = linkedSetOf(name)) {
+        enumerations.add(
+            enumerationTransformer.reverse(ProductTemplateEnumerationDto(name, values, null) to ProductTemplateType.INSURANCE)
+        )
+    }
+
+    private fun page(templateType: ProductTemplateType, afterName: String?, pageable: Pageable) =
+        enumerations.filter { it.productTemplateType == templateType && (afterName == null || it.name > afterName) }
+            .sortedBy { it.name }
+            .take(pageable.pageSize)
+}
@@ -0,0 +1,128 @@
+package io.github.ayushmaanbhav.productFarm.util
+
+import com.fasterxml.jackson.databind.node.IntNode
+import io.github.ayushmaanbhav.common.exception.ValidatorException
+import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
+import io.github.ayushmaanbhav.productFarm.model.Rule
+import io.github.ayushmaanbhav.productFarm.model.RuleDisplayExpression
+import io.github.ayushmaanbhav.ruleEngine.config.Config
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.core.spec.style.StringSpec
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.shouldNotBe
+import io.kotest.matchers.string.shouldContain
+import io.micrometer.core.instrument.simple.SimpleMeterRegistry
+
+class RuleUtilTest : StringSpec() {
+    private val meterRegistry = SimpleMeterRegistry()
+    private val ruleUtil = RuleUtil(Config.objectMapperBuilder().build(), JsonLogicEngine.Builder().build(), EvaluationMetrics(meterRegistry))
+
+    private fun constraintRule(expression: String) = Rule(
+        type = "constraint",
+        inputAttributes = linkedSetOf("value"),
+        outputAttributes = linkedSetOf(RuleUtil.CONSTRAINT_OUTPUT),
+        displayExpression = RuleDisplayExpression(null, expression, null),
+        displayExpressionVersion = "0.1",
+        compiledExpression = expression,
+        description = "value must be even",
+    )
+
+    init {
+        beforeTest { meterRegistry.clear() }
+
+        "executeConstraint should pass a value satisfying the constraint" {
+            // Arrange
+            val rule = constraintRule("""{"valid": {"==": [{"%": [{"var": "value"}, 2]}, 0]}}""")
+
+            // Act
+            val result = ruleUtil.executeConstraint(rule, IntNode(4))
+
+            // Assert
+            result shouldBe true
+        }
+
+        "executeConstraint should fail a value violating the constraint" {
+            // Arrange
+            val rule = constraintRule("""{"valid": {"==": [{"%": [{"var": "value"}, 2]}, 0]}}""")
+
+            // Act
+            val result = ruleUtil.executeConstraint(rule, IntNode(3))
+
+            // Assert
+            result shouldBe false
+        }
+
+        "executeConstraint should accept a constraint returning a bare boolean" {
+            // Arrange
+            val rule = constraintRule("""{"<": [{"var": "value"}, 100]}""")
+
+            // Act & Assert
+            ruleUtil.executeConstraint(rule, IntNode(50)) shouldBe true
+            ruleUtil.executeConstraint(rule, IntNode(150)) shouldBe false
+        }
+
+        "executeConstraint should evaluate the constraint with the given context" {
+            // Arrange
+            val rule = constraintRule("""{"valid": {"<": [{"var": "value"}, {"var": "limit"}]}}""")
+
+            // Act & Assert
+            ruleUtil.executeConstraint(rule, IntNode(50), mapOf("limit" to 100)) shouldBe true
+            ruleUtil.executeConstraint(rule, IntNode(150), mapOf("limit" to 100)) shouldBe false
+        }
+
+        "executeConstraint should report an invalid expression as a validation error" {
+            // Arrange
+            val rule = constraintRule("""{"valid": """)
+
+            // Act
+            val exception = shouldThrow<ValidatorException> { ruleUtil.executeConstraint(rule, IntNode(4)) }
+
+            // Assert
+            exception.code shouldBe 400
+            exception.errors!!.single().message!! shouldContain "value must be even"
+        }
+
+        "executeConstraint should record the constraint evaluation" {
+            // Arrange
+            val rule = constraintRule("""{"valid": {"==": [{"%": [{"var": "value"}, 2]}, 0]}}""")
+
+            // Act
+            ruleUtil.executeConstraint(rule, IntNode(4))
+
+            // Assert
+            meterRegistry.get(EvaluationMetrics.EVALUATION_METRIC)
+                .tag(EvaluationMetrics.TYPE_TAG, EvaluationMetrics.CONSTRAINT_EVALUATION).timer().count() shouldBe 1
+        }
+
+        "canonicalize should give the same string for equal expressions formatted differently" {
+            // Arrange
+            val expression = """{"if": [{">": [{"var": "age"}, 60]}, {"*": [{"var": "premium"}, 1.50]}, 100]}"""
+            val reformatted = """{ "if" : [ { ">" : [ { "var" : "age" }, 6e1 ] }, { "*" : [ { "var" : "premium" }, 1.5 ] }, 100.0 ] }"""
+
+            // Act & Assert
+            ruleUtil.canonicalize(reformatted) shouldBe ruleUtil.canonicalize(expression)
+        }
+
+        "canonicalize should give different strings for different expressions" {
+            // Arrange
+            val expression = """{"if": [{">": [{"var": "age"}, 60]}, {"*": [{"var": "premium"}, 1.5]}, 100]}"""
+            val changed = """{"if": [{">=": [{"var": "age"}, 60]}, {"*": [{"var": "premium"}, 1.5]}, 100]}"""
+
+            // Act & Assert
+            ruleUtil.canonicalize(changed) shouldNotBe ruleUtil.canonicalize(expression)
+        }
+
+        "canonicalize should keep a large integral number decimal" {
+            // Act & Assert
+            ruleUtil.canonicalize("[1e100, 1.0e100]") shouldBe "[1${"0".repeat(100)},1${"0".repeat(100)}]"
+        }
+
+        "canonicalize should reject a number whose digits can not be written" {
+            // Act
+            val exception = shouldThrow<ValidatorException> { ruleUtil.canonicalize("[1e999999999]") }
+
+            // Assert
+            exception.code shouldBe 400
+        }
+    }
+}
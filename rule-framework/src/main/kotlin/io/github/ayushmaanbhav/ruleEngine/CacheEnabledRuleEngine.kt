@@ -17,7 +17,7 @@ class CacheEnabledRuleEngine(private val cache: RuleEngineCache, private val eva
             context.identifier, QueryIdentifier(context.identifier, queries),
             { buildRuleDependencyGraph(context) }, { rdg -> rdg.computeExecutableRules(queries) }
         )
-        return QueryOutput(evaluator.evaluate(rules, input.attributes))
+        return evaluator.evaluate(rules, input.attributes)
     }
 
     private fun buildRuleDependencyGraph(context: QueryContext): DependencyGraph<Rule> {
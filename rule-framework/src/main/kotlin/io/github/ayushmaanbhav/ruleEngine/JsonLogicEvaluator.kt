@@ -2,51 +2,123 @@ package io.github.ayushmaanbhav.ruleEngine
 
 import com.fasterxml.jackson.core.type.TypeReference
 import com.fasterxml.jackson.databind.ObjectMapper
+import io.github.ayushmaanbhav.common.util.CompletableFutureWithCtx
 import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
 import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
 import io.github.ayushmaanbhav.ruleEngine.api.EvaluationEngine
 import io.github.ayushmaanbhav.ruleEngine.config.Config
 import io.github.ayushmaanbhav.ruleEngine.exception.RuleEngineException
+import io.github.ayushmaanbhav.ruleEngine.exception.RuleExecutorUnavailableException
+import io.github.ayushmaanbhav.ruleEngine.model.QueryOutput
 import io.github.ayushmaanbhav.ruleEngine.model.rule.Rule
+import io.micrometer.core.instrument.MeterRegistry
+import io.micrometer.core.instrument.Metrics
+import io.micrometer.core.instrument.Timer
+import java.util.concurrent.ExecutionException
+import java.util.concurrent.ExecutorService
+import java.util.concurrent.RejectedExecutionException
+import java.util.concurrent.SynchronousQueue
+import java.util.concurrent.ThreadPoolExecutor
+import java.util.concurrent.TimeUnit
+import java.util.concurrent.TimeoutException
+import java.util.concurrent.atomic.AtomicInteger
+import java.util.function.Supplier
 import org.apache.logging.log4j.kotlin.Logging
 import org.springframework.stereotype.Component
 
 @Component
-class JsonLogicEvaluator(config: Config, private val jsonLogic: JsonLogicEngine) : EvaluationEngine, Logging {
+class JsonLogicEvaluator(
+    config: Config,
+    private val jsonLogic: JsonLogicEngine,
+    private val meterRegistry: MeterRegistry = Metrics.globalRegistry,
+) : EvaluationEngine, Logging {
     private val objectMapper: ObjectMapper = config.objectMapper
+    private val ruleTimeoutMillis: Long = config.ruleTimeoutMillis
+    private val ruleExecutor: ExecutorService? =
+        if (ruleTimeoutMillis < 0) null else ruleExecutor(config.ruleExecutorThreads)
 
-    override fun evaluate(rules: List<Rule>, attributes: LinkedHashMap<String, Any>): LinkedHashMap<String, Any> {
+    override fun evaluate(rules: List<Rule>, attributes: LinkedHashMap<String, Any>): QueryOutput {
         val visitor = Visitor(attributes)
         rules.forEach(visitor::visit)
         return visitor.result()
     }
 
     private inner class Visitor(attributes: LinkedHashMap<String, Any>) {
-        private val context: LinkedHashMap<String, Any> = LinkedHashMap(attributes)
+        private var context: LinkedHashMap<String, Any> = LinkedHashMap(attributes)
         private val allOutput: LinkedHashMap<String, Any> = LinkedHashMap()
+        private val provenance: LinkedHashMap<String, String> = LinkedHashMap()
+        private val skippedRules: MutableList<String> = mutableListOf()
+        private val warnings: MutableList<String> = mutableListOf()
 
         fun visit(rule: Rule) {
+            val sample = Timer.start(meterRegistry)
+            var outcome = OUTCOME_FAILURE
+            try {
+                outcome = execute(rule)
+            } finally {
+                sample.stop(
+                    Timer.builder(RULE_EXECUTION_METRIC).tags(TYPE_TAG, rule.ruleType(), OUTCOME_TAG, outcome)
+                        .publishPercentileHistogram().register(meterRegistry)
+                )
+            }
+        }
+
+        private fun execute(rule: Rule): String {
             val expression = readExpression(rule.getId(), rule.getExpression())
-            val result = runCatching { jsonLogic.evaluate(expression, context) }
-                .getOrElse { throw RuleEngineException("Error occurred while running rule: ${rule.getId()}", it) }
-            when (result) {
-                is JsonLogicResult.Failure.NullResult -> logger.debug("Ignoring rule gave empty output: ${rule.getId()}")
+            val result = evaluateExpression(rule.getId(), expression) ?: return OUTCOME_SKIPPED
+            return when (result) {
+                is JsonLogicResult.Failure.NullResult -> {
+                    logger.debug("Ignoring rule gave empty output: ${rule.getId()}")
+                    OUTCOME_EMPTY
+                }
+                is JsonLogicResult.Failure.InvalidDate ->
+                    throw RuleEngineException("Got invalid date on running rule: ${rule.getId()}, ${result.value}")
                 is JsonLogicResult.Failure -> throw RuleEngineException("Got failure on running rule: ${rule.getId()}, ${result.javaClass.name}")
 
                 is JsonLogicResult.Success -> {
                     val output = readOutput(rule.getId(), result.value)
                     output.forEach { (key: String, value: Any?) ->
-                        // since "there is only one rule that outputs a particular attribute" and "there are no cycles then"
-                        // override the context with input context
-                        if (context.containsKey(key).not()) {
-                            context[key] = value
-                            allOutput[key] = value
+                        // input attributes override rule outputs, while a rule output
+                        // written by an earlier rule is replaced by the later one
+                        if (provenance.containsKey(key)) {
+                            addWarning("Output attribute: $key of rule: ${provenance[key]} replaced by rule: ${rule.getId()}")
+                        } else if (context.containsKey(key)) {
+                            addWarning("Ignoring output attribute: $key of rule: ${rule.getId()}, already present in input")
+                            return@forEach
                         }
+                        context[key] = value
+                        allOutput[key] = value
+                        provenance[key] = rule.getId()
                     }
+                    OUTCOME_SUCCESS
                 }
             }
         }
 
+        private fun evaluateExpression(ruleId: String, expression: LinkedHashMap<String, Any>): JsonLogicResult? {
+            val executor = ruleExecutor ?: return runCatching { jsonLogic.evaluate(expression, context) }
+                .getOrElse { throw RuleEngineException("Error occurred while running rule: $ruleId", it) }
+            val ruleContext = context
+            val future = try {
+                CompletableFutureWithCtx.submit(executor, Supplier { jsonLogic.evaluate(expression, ruleContext) })
+            } catch (e: RejectedExecutionException) {
+                throw RuleExecutorUnavailableException("Rule executor is unavailable, could not run rule: $ruleId")
+            }
+            return try {
+                future.get(ruleTimeoutMillis, TimeUnit.MILLISECONDS)
+            } catch (e: TimeoutException) {
+                future.cancel(true)
+                // the interrupt is only a request, the timed out rule may keep reading its context,
+                // so the remaining rules continue on a copy instead of mutating it
+                context = LinkedHashMap(context)
+                logger.warn("Skipping rule, timed out after ${ruleTimeoutMillis}ms: $ruleId")
+                skippedRules.add(ruleId)
+                null
+            } catch (e: ExecutionException) {
+                throw RuleEngineException("Error occurred while running rule: $ruleId", e.cause ?: e)
+            }
+        }
+
         private fun readExpression(ruleId: String, expression: String): LinkedHashMap<String, Any> =
             runCatching { objectMapper.readValue(expression, mapTypeReference) }
                 .getOrElse { throw RuleEngineException("Error occurred while reading rule expression: $ruleId", it) }
@@ -55,10 +127,31 @@ class JsonLogicEvaluator(config: Config, private val jsonLogic: JsonLogicEngine)
             runCatching { objectMapper.convertValue(output, mapTypeReference) }
                 .getOrElse { throw RuleEngineException("Error occurred while reading rule engine output: $ruleId", it) }
 
-        fun result(): LinkedHashMap<String, Any> = allOutput
+        private fun addWarning(warning: String) {
+            logger.warn(warning)
+            warnings.add(warning)
+        }
+
+        fun result(): QueryOutput = QueryOutput(allOutput, provenance, skippedRules, warnings)
     }
 
     companion object {
+        const val RULE_EXECUTION_METRIC = "product.farm.rule.execution"
+        private const val TYPE_TAG = "type"
+        private const val OUTCOME_TAG = "outcome"
+        private const val OUTCOME_SUCCESS = "success"
+        private const val OUTCOME_EMPTY = "empty"
+        private const val OUTCOME_SKIPPED = "skipped"
+        private const val OUTCOME_FAILURE = "failure"
         private val mapTypeReference = object : TypeReference<LinkedHashMap<String, Any>>() {}
+
+        // grows beyond the given number of threads under load instead of rejecting rules,
+        // timed out rules are interrupted and stop, so the extra threads are released with them
+        private fun ruleExecutor(threads: Int): ExecutorService {
+            val threadCount = AtomicInteger()
+            return ThreadPoolExecutor(threads, Int.MAX_VALUE, 60, TimeUnit.SECONDS, SynchronousQueue()) { runnable ->
+                Thread(runnable, "rule-evaluator-${threadCount.incrementAndGet()}").apply { isDaemon = true }
+            }.apply { allowCoreThreadTimeOut(true) }
+        }
     }
 }
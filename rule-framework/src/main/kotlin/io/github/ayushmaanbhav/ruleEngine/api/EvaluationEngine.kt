@@ -1,7 +1,8 @@
 package io.github.ayushmaanbhav.ruleEngine.api
 
+import io.github.ayushmaanbhav.ruleEngine.model.QueryOutput
 import io.github.ayushmaanbhav.ruleEngine.model.rule.Rule
 
 interface EvaluationEngine {
-    fun evaluate(rules: List<Rule>, attributes: LinkedHashMap<String, Any>): LinkedHashMap<String, Any>
+    fun evaluate(rules: List<Rule>, attributes: LinkedHashMap<String, Any>): QueryOutput
 }
@@ -15,10 +15,14 @@ interface Config {
     val cachePolicy: CachePolicy
     val maxRuleDgCacheSize: Long
     val maxQueryCacheSize: Long
+    val ruleTimeoutMillis: Long
+    val ruleExecutorThreads: Int
 
     companion object {
         val DEFAULT_USE_CACHE_POLICY = CachePolicy.DISABLED
         const val DEFAULT_MAX_CACHE_SIZE = -1L // no-limit
+        const val DEFAULT_RULE_TIMEOUT_MILLIS = -1L // no-limit
+        const val DEFAULT_RULE_EXECUTOR_THREADS = 16
         fun objectMapperBuilder() = Jackson2ObjectMapperBuilder.json()
             .serializationInclusion(JsonInclude.Include.NON_NULL)
             .featuresToEnable(
@@ -0,0 +1,5 @@
+package io.github.ayushmaanbhav.ruleEngine.exception
+
+import io.github.ayushmaanbhav.common.exception.RetryableException
+
+class RuleExecutorUnavailableException(message: String) : RetryableException(message)
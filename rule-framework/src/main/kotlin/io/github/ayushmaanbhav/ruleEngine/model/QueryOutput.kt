@@ -1,3 +1,13 @@
 package io.github.ayushmaanbhav.ruleEngine.model
 
-data class QueryOutput(val attributes: LinkedHashMap<String, Any>)
+/**
+ * @param provenance id of the rule which produced each output attribute
+ * @param skippedRules ids of the rules which were skipped since they timed out
+ * @param warnings conflicting outputs, e.g. an attribute written by more than one rule
+ */
+data class QueryOutput(
+    val attributes: LinkedHashMap<String, Any>,
+    val provenance: LinkedHashMap<String, String> = LinkedHashMap(),
+    val skippedRules: List<String> = listOf(),
+    val warnings: List<String> = listOf(),
+)
@@ -37,7 +37,7 @@ class CacheEnabledRuleEngineTest : DescribeSpec(), Logging {
                     val rdg = rdgBuilder.captured.invoke()
                     ruleBuilder.captured.invoke(rdg)
                 }
-                every { evaluator.evaluate(rules, queryInput.attributes) } returns LinkedHashMap(mapOf("attribute-2" to true))
+                every { evaluator.evaluate(rules, queryInput.attributes) } returns QueryOutput(LinkedHashMap(mapOf("attribute-2" to true)))
 
                 val actualOutput = cacheEnabledRuleEngine.evaluate(queryContext, queries, queryInput)
 
@@ -47,7 +47,7 @@ class CacheEnabledRuleEngineTest : DescribeSpec(), Logging {
             it("should return expected output when cache is not empty") {
                 val expectedOutput = QueryOutput(LinkedHashMap(mapOf("attribute-2" to false)))
                 every { cache.get(queryContext.identifier, queryIdentifier, any(), any()) } returns rules
-                every { evaluator.evaluate(rules, queryInput.attributes) } returns LinkedHashMap(mapOf("attribute-2" to false))
+                every { evaluator.evaluate(rules, queryInput.attributes) } returns QueryOutput(LinkedHashMap(mapOf("attribute-2" to false)))
 
                 val actualOutput = cacheEnabledRuleEngine.evaluate(queryContext, queries, queryInput)
 
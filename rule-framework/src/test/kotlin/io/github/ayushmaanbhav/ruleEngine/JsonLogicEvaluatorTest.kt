@@ -6,11 +6,15 @@ import io.github.ayushmaanbhav.jsonLogic.JsonLogicEngine
 import io.github.ayushmaanbhav.jsonLogic.JsonLogicResult
 import io.github.ayushmaanbhav.ruleEngine.config.Config
 import io.github.ayushmaanbhav.ruleEngine.exception.RuleEngineException
+import io.github.ayushmaanbhav.ruleEngine.model.QueryOutput
 import io.kotest.assertions.throwables.shouldThrow
 import io.kotest.core.spec.style.BehaviorSpec
 import io.kotest.matchers.shouldBe
 import io.mockk.every
 import io.mockk.mockk
+import java.util.concurrent.Executors
+import java.util.concurrent.TimeUnit
+import java.util.concurrent.atomic.AtomicInteger
 
 class JsonLogicEvaluatorTest : BehaviorSpec({
     given("a JsonLogicEvaluator instance 1") {
@@ -18,6 +22,7 @@ class JsonLogicEvaluatorTest : BehaviorSpec({
         val objectMapper: ObjectMapper = mockk()
         val jsonLogic: JsonLogicEngine = mockk()
         every { config.objectMapper } returns objectMapper
+        every { config.ruleTimeoutMillis } returns Config.DEFAULT_RULE_TIMEOUT_MILLIS
         val evaluator = JsonLogicEvaluator(config, jsonLogic)
 
         val attributes = linkedMapOf<String, Any>("age" to 21, "name" to "John")
@@ -35,8 +40,10 @@ class JsonLogicEvaluatorTest : BehaviorSpec({
             val result = evaluator.evaluate(rules, attributes)
 
             then("the rules should be evaluated and the output should be returned") {
-                result["result1"] shouldBe true
-                result["result2"] shouldBe true
+                result.attributes["result1"] shouldBe true
+                result.attributes["result2"] shouldBe true
+                result.provenance["result1"] shouldBe "rule1"
+                result.provenance["result2"] shouldBe "rule2"
 
                 /*verifySequence {
                     objectMapper.readValue(rule1.getExpression(), any<TypeReference<LinkedHashMap<String, Any>>>())
@@ -55,6 +62,7 @@ class JsonLogicEvaluatorTest : BehaviorSpec({
         val objectMapper: ObjectMapper = mockk()
         val jsonLogic: JsonLogicEngine = mockk()
         every { config.objectMapper } returns objectMapper
+        every { config.ruleTimeoutMillis } returns Config.DEFAULT_RULE_TIMEOUT_MILLIS
         val evaluator = JsonLogicEvaluator(config, jsonLogic)
 
         val attributes = linkedMapOf<String, Any>("age" to 21, "name" to "John")
@@ -80,6 +88,7 @@ class JsonLogicEvaluatorTest : BehaviorSpec({
         val objectMapper: ObjectMapper = mockk()
         val jsonLogic: JsonLogicEngine = mockk()
         every { config.objectMapper } returns objectMapper
+        every { config.ruleTimeoutMillis } returns Config.DEFAULT_RULE_TIMEOUT_MILLIS
         val evaluator = JsonLogicEvaluator(config, jsonLogic)
 
         val attributes = linkedMapOf<String, Any>("age" to 21, "name" to "John")
@@ -104,6 +113,7 @@ class JsonLogicEvaluatorTest : BehaviorSpec({
         val objectMapper: ObjectMapper = mockk()
         val jsonLogic: JsonLogicEngine = mockk()
         every { config.objectMapper } returns objectMapper
+        every { config.ruleTimeoutMillis } returns Config.DEFAULT_RULE_TIMEOUT_MILLIS
         val evaluator = JsonLogicEvaluator(config, jsonLogic)
 
         val attributes = linkedMapOf<String, Any>("age" to 21, "name" to "John")
@@ -128,6 +138,7 @@ class JsonLogicEvaluatorTest : BehaviorSpec({
         val objectMapper: ObjectMapper = mockk()
         val jsonLogic: JsonLogicEngine = mockk()
         every { config.objectMapper } returns objectMapper
+        every { config.ruleTimeoutMillis } returns Config.DEFAULT_RULE_TIMEOUT_MILLIS
         val evaluator = JsonLogicEvaluator(config, jsonLogic)
 
         val attributes = linkedMapOf<String, Any>("age" to 21, "name" to "John")
@@ -142,7 +153,7 @@ class JsonLogicEvaluatorTest : BehaviorSpec({
             val result = evaluator.evaluate(rules, attributes)
 
             then("the rules should be evaluated and the output should be returned") {
-                result.isEmpty() shouldBe true
+                result.attributes.isEmpty() shouldBe true
             }
         }
     }
@@ -152,6 +163,7 @@ class JsonLogicEvaluatorTest : BehaviorSpec({
         val objectMapper: ObjectMapper = mockk()
         val jsonLogic: JsonLogicEngine = mockk()
         every { config.objectMapper } returns objectMapper
+        every { config.ruleTimeoutMillis } returns Config.DEFAULT_RULE_TIMEOUT_MILLIS
         val evaluator = JsonLogicEvaluator(config, jsonLogic)
 
         val attributes = linkedMapOf<String, Any>("age" to 21, "name" to "John", "result" to false)
@@ -168,7 +180,8 @@ class JsonLogicEvaluatorTest : BehaviorSpec({
             val result = evaluator.evaluate(rules, attributes)
 
             then("RuleEngineException should be thrown for duplicate result") {
-                result.isEmpty() shouldBe true
+                result.attributes.isEmpty() shouldBe true
+                result.warnings.size shouldBe 2
             }
         }
     }
@@ -178,6 +191,7 @@ class JsonLogicEvaluatorTest : BehaviorSpec({
         val objectMapper: ObjectMapper = mockk()
         val jsonLogic: JsonLogicEngine = mockk()
         every { config.objectMapper } returns objectMapper
+        every { config.ruleTimeoutMillis } returns Config.DEFAULT_RULE_TIMEOUT_MILLIS
         val evaluator = JsonLogicEvaluator(config, jsonLogic)
 
         val attributes = linkedMapOf<String, Any>("age" to 21, "name" to "John")
@@ -194,4 +208,114 @@ class JsonLogicEvaluatorTest : BehaviorSpec({
             }
         }
     }
+
+    given("a JsonLogicEvaluator instance 8") {
+        val config: Config = mockk()
+        val objectMapper: ObjectMapper = mockk()
+        val jsonLogic: JsonLogicEngine = mockk()
+        every { config.objectMapper } returns objectMapper
+        every { config.ruleTimeoutMillis } returns 100
+        every { config.ruleExecutorThreads } returns 2
+        val evaluator = JsonLogicEvaluator(config, jsonLogic)
+
+        val attributes = linkedMapOf<String, Any>("age" to 21, "name" to "John")
+        val rule1 = RuleImpl("rule1", """{"===": [{"var": "age"}, 21]}""")
+        val rule2 = RuleImpl("rule2", """{"==": [{"var": "name"}, "John"] }""")
+        val rules = listOf(rule1, rule2)
+
+        every { objectMapper.readValue(rule1.getExpression(), any<TypeReference<LinkedHashMap<String, Any>>>()) } returns linkedMapOf("slow" to true)
+        every { objectMapper.readValue(rule2.getExpression(), any<TypeReference<LinkedHashMap<String, Any>>>()) } returns linkedMapOf("fast" to true)
+        every { jsonLogic.evaluate(match { it.containsKey("slow") }, any()) } answers {
+            Thread.sleep(1000)
+            JsonLogicResult.Success(linkedMapOf("output1" to true))
+        }
+        every { jsonLogic.evaluate(match { it.containsKey("fast") }, any()) } answers { JsonLogicResult.Success(linkedMapOf("output2" to true)) }
+        every { objectMapper.convertValue(any(), any<TypeReference<LinkedHashMap<String, Any>>>()) } answers { LinkedHashMap(firstArg<Map<String, Any>>()) }
+
+        `when`("evaluating rules where one rule exceeds the rule timeout") {
+            val result = evaluator.evaluate(rules, attributes)
+
+            then("the slow rule should be skipped and the other rules should still be evaluated") {
+                result.attributes.containsKey("output1") shouldBe false
+                result.attributes["output2"] shouldBe true
+                result.skippedRules shouldBe listOf("rule1")
+            }
+        }
+    }
+
+    given("a JsonLogicEvaluator instance 9") {
+        val config: Config = mockk()
+        val objectMapper: ObjectMapper = mockk()
+        val jsonLogic: JsonLogicEngine = mockk()
+        every { config.objectMapper } returns objectMapper
+        every { config.ruleTimeoutMillis } returns Config.DEFAULT_RULE_TIMEOUT_MILLIS
+        val evaluator = JsonLogicEvaluator(config, jsonLogic)
+
+        val attributes = linkedMapOf<String, Any>("age" to 21, "name" to "John")
+        val rule1 = RuleImpl("rule1", """{"===": [{"var": "age"}, 21]}""")
+        val rule2 = RuleImpl("rule2", """{"==": [{"var": "name"}, "John"] }""")
+        val rules = listOf(rule1, rule2)
+        var i = 1
+
+        every { objectMapper.readValue(any<String>(), any<TypeReference<LinkedHashMap<String, Any>>>()) } returns linkedMapOf("expression" to true)
+        every { jsonLogic.evaluate(any<LinkedHashMap<String, Any>>(), any()) } answers { JsonLogicResult.Success(linkedMapOf("result" to i++)) }
+        every { objectMapper.convertValue(any(), any<TypeReference<LinkedHashMap<String, Any>>>()) } answers { LinkedHashMap(firstArg<Map<String, Any>>()) }
+
+        `when`("evaluating rules which write the same output attribute") {
+            val result = evaluator.evaluate(rules, attributes)
+
+            then("the last rule should be kept as the writer and the conflict should be reported") {
+                result.attributes["result"] shouldBe 2
+                result.provenance["result"] shouldBe "rule2"
+                result.warnings shouldBe listOf("Output attribute: result of rule: rule1 replaced by rule: rule2")
+            }
+        }
+    }
+
+    given("a JsonLogicEvaluator instance 10") {
+        val config: Config = mockk()
+        val objectMapper: ObjectMapper = mockk()
+        val jsonLogic: JsonLogicEngine = mockk()
+        every { config.objectMapper } returns objectMapper
+        every { config.ruleTimeoutMillis } returns 100
+        every { config.ruleExecutorThreads } returns 1
+        val evaluator = JsonLogicEvaluator(config, jsonLogic)
+
+        val attributes = linkedMapOf<String, Any>("age" to 21, "name" to "John")
+        val rule1 = RuleImpl("rule1", """{"===": [{"var": "age"}, 21]}""")
+        val rule2 = RuleImpl("rule2", """{"==": [{"var": "name"}, "John"] }""")
+        val rules = listOf(rule1, rule2)
+        val stoppedRules = AtomicInteger()
+
+        every { objectMapper.readValue(rule1.getExpression(), any<TypeReference<LinkedHashMap<String, Any>>>()) } returns linkedMapOf("slow" to true)
+        every { objectMapper.readValue(rule2.getExpression(), any<TypeReference<LinkedHashMap<String, Any>>>()) } returns linkedMapOf("fast" to true)
+        every { jsonLogic.evaluate(match { it.containsKey("slow") }, any()) } answers {
+            while (!Thread.currentThread().isInterrupted) {
+                Thread.onSpinWait()
+            }
+            stoppedRules.incrementAndGet()
+            JsonLogicResult.Failure.Interrupted
+        }
+        every { jsonLogic.evaluate(match { it.containsKey("fast") }, any()) } answers { JsonLogicResult.Success(linkedMapOf("output2" to true)) }
+        every { objectMapper.convertValue(any(), any<TypeReference<LinkedHashMap<String, Any>>>()) } answers { LinkedHashMap(firstArg<Map<String, Any>>()) }
+
+        `when`("evaluating concurrently while slow rules hold more threads than the executor keeps") {
+            val callers = Executors.newFixedThreadPool(8)
+            val results = try {
+                (1..8).map { callers.submit<QueryOutput> { evaluator.evaluate(rules, attributes) } }.map { it.get(5, TimeUnit.SECONDS) }
+            } finally {
+                callers.shutdown()
+            }
+
+            then("every evaluation should complete and the timed out rules should stop") {
+                results.map { it.attributes["output2"] } shouldBe List(8) { true }
+                results.map { it.skippedRules } shouldBe List(8) { listOf("rule1") }
+                val deadline = System.currentTimeMillis() + 5000
+                while (stoppedRules.get() < 8 && System.currentTimeMillis() < deadline) {
+                    Thread.sleep(10)
+                }
+                stoppedRules.get() shouldBe 8
+            }
+        }
+    }
 })